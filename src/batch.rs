@@ -0,0 +1,322 @@
+use crate::{
+    vec3, Affine3, Matrix3, Motion3, Projective3, Rotation3, Scalar, Similarity3, Vector3,
+};
+
+/// The number of vectors processed together by a [`Kernel`]. Vectors are transposed into
+/// structure-of-arrays form within each chunk, so that the compiler can vectorize the arithmetic.
+const LANES: usize = 8;
+
+/// A transform which can be applied to a fixed-size chunk of vectors at once.
+trait Kernel {
+    /// Applies this transform to each of the given vectors.
+    fn apply<const N: usize>(&self, input: &[Vector3; N]) -> [Vector3; N];
+}
+
+/// Transposes a chunk of vectors into structure-of-arrays form.
+#[inline(always)]
+fn transpose<const N: usize>(input: &[Vector3; N]) -> [[Scalar; N]; 3] {
+    [
+        core::array::from_fn(|i| input[i].x),
+        core::array::from_fn(|i| input[i].y),
+        core::array::from_fn(|i| input[i].z),
+    ]
+}
+
+/// Applies `kernel` to each vector in `input`, writing the results to `output`.
+fn map_slice(kernel: &impl Kernel, input: &[Vector3], output: &mut [Vector3]) {
+    assert_eq!(input.len(), output.len(), "slice lengths differ");
+    let mut input = input.chunks_exact(LANES);
+    let mut output = output.chunks_exact_mut(LANES);
+    for (src, dst) in (&mut input).zip(&mut output) {
+        dst.copy_from_slice(&kernel.apply::<LANES>(src.try_into().unwrap()));
+    }
+    for (src, dst) in input.remainder().iter().zip(output.into_remainder()) {
+        *dst = kernel.apply(&[*src])[0];
+    }
+}
+
+/// Applies `kernel` to each vector in `data`, in place.
+fn map_slice_in_place(kernel: &impl Kernel, data: &mut [Vector3]) {
+    let mut chunks = data.chunks_exact_mut(LANES);
+    for chunk in &mut chunks {
+        let res = kernel.apply::<LANES>((&*chunk).try_into().unwrap());
+        chunk.copy_from_slice(&res);
+    }
+    for value in chunks.into_remainder() {
+        *value = kernel.apply(&[*value])[0];
+    }
+}
+
+/// A [`Kernel`] for an affine transform.
+struct AffineKernel {
+    /// The columns of the linear component of the transform.
+    linear: [[Scalar; 3]; 3],
+
+    /// The offset of the transform.
+    offset: [Scalar; 3],
+}
+
+impl AffineKernel {
+    /// Constructs an [`AffineKernel`] from the linear and offset components of a transform.
+    fn new(linear: Matrix3, offset: Vector3) -> Self {
+        let col = |v: Vector3| [v.x, v.y, v.z];
+        Self {
+            linear: [col(linear.x), col(linear.y), col(linear.z)],
+            offset: col(offset),
+        }
+    }
+}
+
+impl Kernel for AffineKernel {
+    #[inline]
+    fn apply<const N: usize>(&self, input: &[Vector3; N]) -> [Vector3; N] {
+        let [x, y, z] = transpose(input);
+        let [m_x, m_y, m_z] = &self.linear;
+        let m_w = &self.offset;
+        let [r_x, r_y, r_z]: [[Scalar; N]; 3] = core::array::from_fn(|r| {
+            core::array::from_fn(|i| m_x[r] * x[i] + m_y[r] * y[i] + m_z[r] * z[i] + m_w[r])
+        });
+        core::array::from_fn(|i| vec3(r_x[i], r_y[i], r_z[i]))
+    }
+}
+
+/// A [`Kernel`] for a projective transform, including the perspective divide.
+struct ProjectiveKernel {
+    /// The columns of the 4x4 matrix for the transform.
+    matrix: [[Scalar; 4]; 4],
+}
+
+impl Kernel for ProjectiveKernel {
+    #[inline]
+    fn apply<const N: usize>(&self, input: &[Vector3; N]) -> [Vector3; N] {
+        let [x, y, z] = transpose(input);
+        let [m_x, m_y, m_z, m_w] = &self.matrix;
+        let [r_x, r_y, r_z, r_w]: [[Scalar; N]; 4] = core::array::from_fn(|r| {
+            core::array::from_fn(|i| m_x[r] * x[i] + m_y[r] * y[i] + m_z[r] * z[i] + m_w[r])
+        });
+        core::array::from_fn(|i| {
+            let inv_w = 1.0 / r_w[i];
+            vec3(r_x[i] * inv_w, r_y[i] * inv_w, r_z[i] * inv_w)
+        })
+    }
+}
+
+impl Rotation3 {
+    /// Applies this rotation to each vector in `input`, writing the results to `output`.
+    ///
+    /// This is equivalent to multiplying each vector individually, but processes them in chunks
+    /// so that the compiler can vectorize the arithmetic.
+    ///
+    /// # Panics
+    /// Panics if `input` and `output` don't have the same length.
+    pub fn transform_vectors(&self, input: &[Vector3], output: &mut [Vector3]) {
+        Affine3::from(*self).transform_vectors(input, output)
+    }
+
+    /// Applies this rotation to each vector in `data`, in place.
+    pub fn transform_vectors_in_place(&self, data: &mut [Vector3]) {
+        Affine3::from(*self).transform_vectors_in_place(data)
+    }
+}
+
+impl Motion3 {
+    /// Applies this motion to each point in `input`, writing the results to `output`.
+    ///
+    /// This is equivalent to multiplying each point individually, but processes them in chunks
+    /// so that the compiler can vectorize the arithmetic.
+    ///
+    /// # Panics
+    /// Panics if `input` and `output` don't have the same length.
+    pub fn transform_points(&self, input: &[Vector3], output: &mut [Vector3]) {
+        Affine3::from(*self).transform_points(input, output)
+    }
+
+    /// Applies this motion to each point in `data`, in place.
+    pub fn transform_points_in_place(&self, data: &mut [Vector3]) {
+        Affine3::from(*self).transform_points_in_place(data)
+    }
+
+    /// Applies the linear component of this motion to each vector in `input`, writing the
+    /// results to `output`.
+    ///
+    /// # Panics
+    /// Panics if `input` and `output` don't have the same length.
+    pub fn transform_vectors(&self, input: &[Vector3], output: &mut [Vector3]) {
+        self.rotation.transform_vectors(input, output)
+    }
+
+    /// Applies the linear component of this motion to each vector in `data`, in place.
+    pub fn transform_vectors_in_place(&self, data: &mut [Vector3]) {
+        self.rotation.transform_vectors_in_place(data)
+    }
+}
+
+impl Similarity3 {
+    /// Applies this similarity to each point in `input`, writing the results to `output`.
+    ///
+    /// This is equivalent to multiplying each point individually, but processes them in chunks
+    /// so that the compiler can vectorize the arithmetic.
+    ///
+    /// # Panics
+    /// Panics if `input` and `output` don't have the same length.
+    pub fn transform_points(&self, input: &[Vector3], output: &mut [Vector3]) {
+        Affine3::from(*self).transform_points(input, output)
+    }
+
+    /// Applies this similarity to each point in `data`, in place.
+    pub fn transform_points_in_place(&self, data: &mut [Vector3]) {
+        Affine3::from(*self).transform_points_in_place(data)
+    }
+
+    /// Applies the linear component of this similarity to each vector in `input`, writing the
+    /// results to `output`.
+    ///
+    /// # Panics
+    /// Panics if `input` and `output` don't have the same length.
+    pub fn transform_vectors(&self, input: &[Vector3], output: &mut [Vector3]) {
+        Affine3::from(*self).transform_vectors(input, output)
+    }
+
+    /// Applies the linear component of this similarity to each vector in `data`, in place.
+    pub fn transform_vectors_in_place(&self, data: &mut [Vector3]) {
+        Affine3::from(*self).transform_vectors_in_place(data)
+    }
+}
+
+impl Affine3 {
+    /// Applies this transform to each point in `input`, writing the results to `output`.
+    ///
+    /// This is equivalent to multiplying each point individually, but processes them in chunks
+    /// so that the compiler can vectorize the arithmetic.
+    ///
+    /// # Panics
+    /// Panics if `input` and `output` don't have the same length.
+    pub fn transform_points(&self, input: &[Vector3], output: &mut [Vector3]) {
+        map_slice(&AffineKernel::new(self.linear, self.offset), input, output)
+    }
+
+    /// Applies this transform to each point in `data`, in place.
+    pub fn transform_points_in_place(&self, data: &mut [Vector3]) {
+        map_slice_in_place(&AffineKernel::new(self.linear, self.offset), data)
+    }
+
+    /// Applies the linear component of this transform to each vector in `input`, writing the
+    /// results to `output`.
+    ///
+    /// # Panics
+    /// Panics if `input` and `output` don't have the same length.
+    pub fn transform_vectors(&self, input: &[Vector3], output: &mut [Vector3]) {
+        let kernel = AffineKernel::new(self.linear, vec3(0.0, 0.0, 0.0));
+        map_slice(&kernel, input, output)
+    }
+
+    /// Applies the linear component of this transform to each vector in `data`, in place.
+    pub fn transform_vectors_in_place(&self, data: &mut [Vector3]) {
+        let kernel = AffineKernel::new(self.linear, vec3(0.0, 0.0, 0.0));
+        map_slice_in_place(&kernel, data)
+    }
+}
+
+impl Projective3 {
+    /// Gets the [`ProjectiveKernel`] for this transform.
+    fn kernel(&self) -> ProjectiveKernel {
+        let m = self.as_matrix();
+        let col = |v: crate::Vector4| [v.x, v.y, v.z, v.w];
+        ProjectiveKernel {
+            matrix: [col(m.x), col(m.y), col(m.z), col(m.w)],
+        }
+    }
+
+    /// Applies this transform, including the perspective divide, to each point in `input`,
+    /// writing the results to `output`.
+    ///
+    /// This is equivalent to multiplying each point individually, but processes them in chunks
+    /// so that the compiler can vectorize the arithmetic.
+    ///
+    /// # Panics
+    /// Panics if `input` and `output` don't have the same length.
+    pub fn project_points(&self, input: &[Vector3], output: &mut [Vector3]) {
+        map_slice(&self.kernel(), input, output)
+    }
+
+    /// Applies this transform, including the perspective divide, to each point in `data`, in
+    /// place.
+    pub fn project_points_in_place(&self, data: &mut [Vector3]) {
+        map_slice_in_place(&self.kernel(), data)
+    }
+}
+
+#[test]
+fn test_transform_points() {
+    let input: Vec<_> = (0..19)
+        .map(|i| {
+            let t = i as Scalar;
+            vec3(t.sin() * 3.0, (t * 0.7).cos() - 0.5, -2.0 - t * 0.25)
+        })
+        .collect();
+    let motion = Motion3 {
+        rotation: Rotation3::from_euler(vec3(0.5, -1.0, 0.2)),
+        offset: vec3(1.0, 2.0, -3.0),
+    };
+    let mut output = vec![vec3(0.0, 0.0, 0.0); input.len()];
+    motion.transform_points(&input, &mut output);
+    let mut in_place = input.clone();
+    motion.transform_points_in_place(&mut in_place);
+    for ((p, a), b) in input.iter().zip(output.iter()).zip(in_place.iter()) {
+        approx::assert_relative_eq!(*a, motion * *p, epsilon = 1e-5);
+        assert_eq!(a, b);
+    }
+    motion.transform_vectors(&input, &mut output);
+    for (p, a) in input.iter().zip(output.iter()) {
+        approx::assert_relative_eq!(*a, motion.rotation * *p, epsilon = 1e-5);
+    }
+
+    // The offset should not be applied to vectors
+    let similarity = Similarity3 {
+        rotation: motion.rotation,
+        scaling: 2.5,
+        offset: vec3(-4.0, 0.5, 7.0),
+    };
+    similarity.transform_vectors(&input, &mut output);
+    let mut in_place = input.clone();
+    similarity.transform_vectors_in_place(&mut in_place);
+    for ((p, a), b) in input.iter().zip(output.iter()).zip(in_place.iter()) {
+        approx::assert_relative_eq!(*a, similarity.linear() * *p, epsilon = 1e-4);
+        assert_eq!(a, b);
+    }
+    let affine = Affine3 {
+        linear: Matrix3 {
+            x: vec3(2.0, 0.5, 0.0),
+            y: vec3(-0.3, 1.0, 0.8),
+            z: vec3(0.1, 0.0, -1.5),
+        },
+        offset: vec3(-7.0, 3.0, 12.0),
+    };
+    affine.transform_vectors(&input, &mut output);
+    let mut in_place = input.clone();
+    affine.transform_vectors_in_place(&mut in_place);
+    for ((p, a), b) in input.iter().zip(output.iter()).zip(in_place.iter()) {
+        approx::assert_relative_eq!(*a, affine.linear * *p, epsilon = 1e-5);
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_project_points() {
+    use crate::conv::Perspective;
+    let proj = Projective3::perspective(1.5, 1.0, 0.5, 50.0);
+    let input: Vec<_> = (0..19)
+        .map(|i| {
+            let t = i as Scalar;
+            vec3(t.sin() * 3.0, (t * 0.7).cos() - 0.5, -2.0 - t * 0.25)
+        })
+        .collect();
+    let mut output = vec![vec3(0.0, 0.0, 0.0); input.len()];
+    proj.project_points(&input, &mut output);
+    let mut in_place = input.clone();
+    proj.project_points_in_place(&mut in_place);
+    for ((p, a), b) in input.iter().zip(output.iter()).zip(in_place.iter()) {
+        approx::assert_relative_eq!(*a, proj * *p, epsilon = 1e-5);
+        assert_eq!(a, b);
+    }
+}
@@ -0,0 +1,319 @@
+//! This module defines common camera controllers. Each controller holds its own state and produces
+//! a camera-to-world [`Motion3`], following the conventions of the [`crate::conv`] module (i.e.
+//! positive X points right, positive Y points up and negative Z points forward).
+use crate::time::Duration;
+use crate::{math, vec3, Motion3, Rotation2, Rotation3, Scalar, Vector2, Vector3};
+
+/// A camera controller which can produce a camera-to-world transform.
+pub trait Camera {
+    /// Gets the camera-to-world transform for this camera.
+    fn camera_to_world(&self) -> Motion3;
+
+    /// Gets the world-to-view transform for this camera, which is the inverse of
+    /// [`Camera::camera_to_world`].
+    #[inline]
+    fn view(&self) -> Motion3 {
+        self.camera_to_world().inverse()
+    }
+}
+
+/// The default limit on the absolute value of pitch angles, chosen to be slightly less than 90
+/// degrees so that the camera never looks straight up or down.
+pub const DEFAULT_PITCH_LIMIT: Scalar = crate::PI / 2.0 - 0.001;
+
+/// Gets the rotation for the given yaw and pitch angles, in radians.
+fn yaw_pitch_rotation(yaw: Scalar, pitch: Scalar) -> Rotation3 {
+    Rotation3::about(vec3(0.0, 1.0, 0.0), Rotation2::from_angle(yaw))
+        * Rotation3::about(vec3(1.0, 0.0, 0.0), Rotation2::from_angle(pitch))
+}
+
+/// A camera which orbits around a target point at a fixed distance.
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrbitCamera {
+    /// The point the camera is looking at.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
+    pub target: Vector3,
+
+    /// The distance between the camera and the target.
+    pub distance: Scalar,
+
+    /// The counter-clockwise rotation of the camera about the Y axis, in radians. At zero, the
+    /// camera looks along negative Z.
+    pub yaw: Scalar,
+
+    /// The upward rotation of the camera, in radians. This should be within
+    /// `[-pitch_limit, pitch_limit]`.
+    pub pitch: Scalar,
+
+    /// The limit on the absolute value of `pitch`.
+    pub pitch_limit: Scalar,
+}
+
+impl OrbitCamera {
+    /// Constructs an [`OrbitCamera`] looking at the given target from the given distance.
+    pub fn new(target: Vector3, distance: Scalar) -> Self {
+        Self {
+            target,
+            distance,
+            yaw: 0.0,
+            pitch: 0.0,
+            pitch_limit: DEFAULT_PITCH_LIMIT,
+        }
+    }
+
+    /// Rotates the camera about the target by the given yaw and pitch angles, in radians.
+    pub fn rotate(&mut self, delta_yaw: Scalar, delta_pitch: Scalar) {
+        self.yaw += delta_yaw;
+        self.pitch = (self.pitch + delta_pitch).clamp(-self.pitch_limit, self.pitch_limit);
+    }
+
+    /// Multiplies the distance between the camera and the target by the given factor.
+    pub fn zoom(&mut self, factor: Scalar) {
+        self.distance *= factor;
+    }
+
+    /// Moves the target, and the camera with it, by the given offset in the camera's image plane.
+    pub fn pan(&mut self, offset: Vector2) {
+        let rotation = yaw_pitch_rotation(self.yaw, self.pitch);
+        self.target = self.target + rotation * vec3(offset.x, offset.y, 0.0);
+    }
+}
+
+impl Camera for OrbitCamera {
+    fn camera_to_world(&self) -> Motion3 {
+        let rotation = yaw_pitch_rotation(self.yaw, self.pitch);
+        Motion3 {
+            rotation,
+            offset: self.target + rotation * vec3(0.0, 0.0, self.distance),
+        }
+    }
+}
+
+/// A camera which orbits around a target point at a fixed distance, controlled by dragging
+/// points on a virtual sphere.
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArcballCamera {
+    /// The point the camera is looking at.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
+    pub target: Vector3,
+
+    /// The distance between the camera and the target.
+    pub distance: Scalar,
+
+    /// The orientation of the camera.
+    pub rotation: Rotation3,
+}
+
+impl ArcballCamera {
+    /// Constructs an [`ArcballCamera`] looking at the given target from the given distance.
+    pub fn new(target: Vector3, distance: Scalar) -> Self {
+        Self {
+            target,
+            distance,
+            rotation: Rotation3::IDENTITY,
+        }
+    }
+
+    /// Updates the camera in response to dragging from the point `from` to the point `to`, both
+    /// given in normalized device coordinates. The scene appears to rotate with the drag.
+    ///
+    /// Dragging between opposite points on the rim of the arcball rotates half a turn about the
+    /// view axis.
+    pub fn drag(&mut self, from: Vector2, to: Vector2) {
+        let a = arcball_point(from);
+        let b = arcball_point(to);
+        let w = 1.0 + a.dot(&b);
+        let v = a.cross(&b);
+        let norm = math::sqrt(w * w + v.norm_squared());
+        let delta = if norm > 1e-4 {
+            Rotation3::new_unchecked(w / norm, v.x / norm, v.y / norm, v.z / norm)
+        } else {
+            // The points are (nearly) antipodal, which is only possible on the rim, so the
+            // rotation axis is undefined. Use the view axis, which is perpendicular to both
+            Rotation3::new_unchecked(0.0, 0.0, 0.0, 1.0)
+        };
+        self.rotation = self.rotation * delta.inverse();
+    }
+
+    /// Multiplies the distance between the camera and the target by the given factor.
+    pub fn zoom(&mut self, factor: Scalar) {
+        self.distance *= factor;
+    }
+}
+
+/// Projects a point in normalized device coordinates onto the unit arcball sphere.
+fn arcball_point(point: Vector2) -> Vector3 {
+    let norm_sqr = point.x * point.x + point.y * point.y;
+    if norm_sqr <= 1.0 {
+        vec3(point.x, point.y, math::sqrt(1.0 - norm_sqr))
+    } else {
+        let norm = math::sqrt(norm_sqr);
+        vec3(point.x / norm, point.y / norm, 0.0)
+    }
+}
+
+impl Camera for ArcballCamera {
+    fn camera_to_world(&self) -> Motion3 {
+        Motion3 {
+            rotation: self.rotation,
+            offset: self.target + self.rotation * vec3(0.0, 0.0, self.distance),
+        }
+    }
+}
+
+/// A camera which is controlled by yaw and pitch angles and moves horizontally, as in a
+/// first-person game.
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FirstPersonCamera {
+    /// The position of the camera.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
+    pub position: Vector3,
+
+    /// The counter-clockwise rotation of the camera about the Y axis, in radians. At zero, the
+    /// camera looks along negative Z.
+    pub yaw: Scalar,
+
+    /// The upward rotation of the camera, in radians. This should be within
+    /// `[-pitch_limit, pitch_limit]`.
+    pub pitch: Scalar,
+
+    /// The limit on the absolute value of `pitch`.
+    pub pitch_limit: Scalar,
+}
+
+impl FirstPersonCamera {
+    /// Constructs a [`FirstPersonCamera`] at the given position, looking along negative Z.
+    pub fn new(position: Vector3) -> Self {
+        Self {
+            position,
+            yaw: 0.0,
+            pitch: 0.0,
+            pitch_limit: DEFAULT_PITCH_LIMIT,
+        }
+    }
+
+    /// Rotates the camera by the given yaw and pitch angles, in radians, clamping the pitch to
+    /// `pitch_limit`.
+    pub fn look(&mut self, delta_yaw: Scalar, delta_pitch: Scalar) {
+        self.yaw += delta_yaw;
+        self.pitch = (self.pitch + delta_pitch).clamp(-self.pitch_limit, self.pitch_limit);
+    }
+
+    /// Moves the camera with the given velocity for the given amount of time. The velocity is
+    /// given relative to the camera's heading, ignoring pitch, so that moving "forward" (along
+    /// negative Z) does not change the camera's height.
+    pub fn walk(&mut self, velocity: Vector3, dt: Duration) {
+        let heading = Rotation3::about(vec3(0.0, 1.0, 0.0), Rotation2::from_angle(self.yaw));
+        self.position = self.position + heading * (velocity * dt.as_secs_scalar());
+    }
+}
+
+impl Camera for FirstPersonCamera {
+    fn camera_to_world(&self) -> Motion3 {
+        Motion3 {
+            rotation: yaw_pitch_rotation(self.yaw, self.pitch),
+            offset: self.position,
+        }
+    }
+}
+
+/// A camera which can rotate and move freely in all directions, as in a flight simulator.
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FreeFlyCamera {
+    /// The position of the camera.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
+    pub position: Vector3,
+
+    /// The orientation of the camera.
+    pub rotation: Rotation3,
+}
+
+impl FreeFlyCamera {
+    /// Constructs a [`FreeFlyCamera`] from its camera-to-world transform.
+    pub fn new(camera_to_world: Motion3) -> Self {
+        Self {
+            position: camera_to_world.offset,
+            rotation: camera_to_world.rotation,
+        }
+    }
+
+    /// Rotates the camera with the given angular velocity, in radians per second, for the given
+    /// amount of time. The angular velocity is given relative to the camera, so that e.g. a
+    /// positive Z component rolls the camera counter-clockwise.
+    pub fn rotate(&mut self, angular_velocity: Vector3, dt: Duration) {
+        let delta = Rotation3::from_euler(angular_velocity * dt.as_secs_scalar());
+        self.rotation = self.rotation * delta;
+    }
+
+    /// Moves the camera with the given velocity, relative to the camera, for the given amount of
+    /// time.
+    pub fn fly(&mut self, velocity: Vector3, dt: Duration) {
+        self.position = self.position + self.rotation * (velocity * dt.as_secs_scalar());
+    }
+}
+
+impl Camera for FreeFlyCamera {
+    fn camera_to_world(&self) -> Motion3 {
+        Motion3 {
+            rotation: self.rotation,
+            offset: self.position,
+        }
+    }
+}
+
+#[test]
+fn test_orbit() {
+    let mut camera = OrbitCamera::new(vec3(1.0, 2.0, 3.0), 5.0);
+    camera.rotate(0.7, 0.4);
+    let view = camera.view();
+    approx::assert_relative_eq!(view * camera.target, vec3(0.0, 0.0, -5.0), epsilon = 1e-5);
+    camera.rotate(0.0, 10.0);
+    assert_eq!(camera.pitch, DEFAULT_PITCH_LIMIT);
+}
+
+#[test]
+fn test_arcball() {
+    let mut camera = ArcballCamera::new(vec3(1.0, 2.0, 3.0), 5.0);
+    camera.drag(crate::vec2(0.0, 0.0), crate::vec2(0.5, 0.0));
+    let view = camera.view();
+    approx::assert_relative_eq!(view * camera.target, vec3(0.0, 0.0, -5.0), epsilon = 1e-5);
+
+    // Dragging right should rotate the scene to the right, i.e. move the camera to the left
+    let pos = camera.camera_to_world().offset;
+    assert!(pos.x < camera.target.x);
+}
+
+#[test]
+fn test_arcball_antipodal() {
+    let mut camera = ArcballCamera::new(vec3(1.0, 2.0, 3.0), 5.0);
+    camera.drag(crate::vec2(-2.0, 0.0), crate::vec2(2.0, 0.0));
+    let view = camera.view();
+    assert!(view.offset.x.is_finite() && view.offset.y.is_finite() && view.offset.z.is_finite());
+    approx::assert_relative_eq!(view * camera.target, vec3(0.0, 0.0, -5.0), epsilon = 1e-5);
+
+    // The drag should map the starting point on the rim onto the end point
+    let dir = camera.rotation * vec3(1.0, 0.0, 0.0);
+    approx::assert_relative_eq!(dir, vec3(-1.0, 0.0, 0.0), epsilon = 1e-5);
+}
+
+#[test]
+fn test_first_person() {
+    let mut camera = FirstPersonCamera::new(vec3(0.0, 1.0, 0.0));
+    camera.look(crate::PI / 2.0, 10.0);
+    assert_eq!(camera.pitch, DEFAULT_PITCH_LIMIT);
+    camera.walk(vec3(0.0, 0.0, -2.0), Duration::from_millis(500));
+    approx::assert_relative_eq!(camera.position, vec3(-1.0, 1.0, 0.0), epsilon = 1e-5);
+}
+
+#[test]
+fn test_free_fly() {
+    let mut camera = FreeFlyCamera::default();
+    camera.rotate(vec3(0.0, crate::PI, 0.0), Duration::from_millis(500));
+    camera.rotate(vec3(0.0, 0.0, 0.0), Duration::from_millis(500));
+    camera.fly(vec3(0.0, 0.0, -4.0), Duration::from_millis(250));
+    approx::assert_relative_eq!(camera.position, vec3(-1.0, 0.0, 0.0), epsilon = 1e-5);
+}
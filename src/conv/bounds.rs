@@ -0,0 +1,145 @@
+use crate::shape::{Box2, Box3, Sphere3};
+use crate::{math, vec2, vec3, vec4, Projective3, Scalar, Vector2, Vector4};
+
+/// Gets a bounding rectangle, in normalized device coordinates, for the projection of the given
+/// box, or returns [`None`] if the box is entirely behind the near plane.
+///
+/// Parts of the box behind the near plane are clipped before projection, so this gives correct
+/// results for boxes which cross the near plane or surround the camera. The result is not clamped
+/// to the visible range of normalized device coordinates.
+pub fn project_box_bounds(proj: &Projective3, bx: &Box3) -> Option<Box2> {
+    let corners = bx.corners().map(|p| *proj * vec4(p.x, p.y, p.z, 1.0));
+    let mut bounds: Option<Box2> = None;
+    let mut include = |clip: Vector4| {
+        let point = Box2::only(vec2(clip.x / clip.w, clip.y / clip.w));
+        bounds = Some(bounds.map_or(point, |bounds| bounds.bound(point)));
+    };
+    for (i, a) in corners.iter().enumerate() {
+        let a_dist = near_dist(*a);
+        if a_dist >= 0.0 {
+            include(*a);
+        }
+        for bit in [0b001, 0b010, 0b100] {
+            if i & bit == 0 {
+                let b = corners[i | bit];
+                let b_dist = near_dist(b);
+                if (a_dist < 0.0) != (b_dist < 0.0) {
+                    let t = a_dist / (a_dist - b_dist);
+                    include(*a + (b - *a) * t);
+                }
+            }
+        }
+    }
+    bounds
+}
+
+/// Gets the signed distance, in clip space, from the near plane to the given point. This is
+/// positive for points in front of the near plane.
+#[inline]
+fn near_dist(clip: Vector4) -> Scalar {
+    clip.w - clip.z
+}
+
+/// Gets a bounding rectangle, in normalized device coordinates, for the projection of the given
+/// sphere, or returns [`None`] if the sphere is entirely behind the near plane.
+///
+/// When the sphere is entirely in front of the near plane, this gives the exact bounds of its
+/// projection (which is an ellipse). Otherwise, this falls back to the bounds of the projection of
+/// the sphere's bounding box, clipped to the near plane. The result is not clamped to the visible
+/// range of normalized device coordinates.
+pub fn project_sphere_bounds(proj: &Projective3, sphere: &Sphere3) -> Option<Box2> {
+    let c = sphere.center;
+    let r = sphere.radius;
+    let (r_0, r_1, r_2, r_3) = (proj.row(0), proj.row(1), proj.row(2), proj.row(3));
+
+    // Check whether the sphere is entirely in front of the near plane
+    let near = r_3 - r_2;
+    let near_normal = vec3(near.x, near.y, near.z);
+    let near_dist = near_normal.dot(&c) + near.w;
+    if near_dist <= 0.0 || near_dist * near_dist <= r * r * near_normal.norm_squared() {
+        let bx = Box3::from_min_max(c - vec3(r, r, r), c + vec3(r, r, r));
+        return project_box_bounds(proj, &bx);
+    }
+
+    // Evaluates the bilinear form of the dual quadric of the sphere on the given planes. A plane
+    // is tangent to the sphere exactly when this is zero for the plane paired with itself.
+    let dual = |a: Vector4, b: Vector4| {
+        let a_n = vec3(a.x, a.y, a.z);
+        let b_n = vec3(b.x, b.y, b.z);
+        let (a_c, b_c) = (a_n.dot(&c), b_n.dot(&c));
+        r * r * a_n.dot(&b_n) - a_c * b_c - a.w * b_c - b.w * a_c - a.w * b.w
+    };
+
+    // Find the values `k` such that the plane corresponding to `row = k * w` is tangent to the
+    // sphere
+    let c_ww = dual(r_3, r_3);
+    let tangents = |row: Vector4| -> Vector2 {
+        let c_rr = dual(row, row);
+        let c_rw = dual(row, r_3);
+        let disc = math::sqrt((c_rw * c_rw - c_rr * c_ww).max(0.0));
+        let a = (c_rw + disc) / c_ww;
+        let b = (c_rw - disc) / c_ww;
+        vec2(a.min(b), a.max(b))
+    };
+    let x = tangents(r_0);
+    let y = tangents(r_1);
+    Some(Box2::from_min_max(vec2(x.x, y.x), vec2(x.y, y.y)))
+}
+
+#[test]
+fn test_project_box_bounds() {
+    use super::Perspective;
+    let proj = Projective3::perspective(1.0, crate::PI / 2.0, 1.0, 100.0);
+
+    // In front of the near plane
+    let bx = Box3::from_min_max(vec3(1.0, -1.0, -4.0), vec3(2.0, 1.0, -2.0));
+    let bounds = project_box_bounds(&proj, &bx).unwrap();
+    approx::assert_relative_eq!(bounds.min(), vec2(0.25, -0.5), epsilon = 1e-5);
+    approx::assert_relative_eq!(bounds.max(), vec2(1.0, 0.5), epsilon = 1e-5);
+
+    // Crossing the near plane
+    let bx = Box3::from_min_max(vec3(1.0, -1.0, -5.0), vec3(2.0, 1.0, 5.0));
+    let bounds = project_box_bounds(&proj, &bx).unwrap();
+    approx::assert_relative_eq!(bounds.min(), vec2(0.2, -1.0), epsilon = 1e-5);
+    approx::assert_relative_eq!(bounds.max(), vec2(2.0, 1.0), epsilon = 1e-5);
+
+    // Behind the near plane
+    let bx = Box3::from_min_max(vec3(1.0, -1.0, -0.5), vec3(2.0, 1.0, 5.0));
+    assert_eq!(project_box_bounds(&proj, &bx), None);
+}
+
+#[test]
+fn test_project_sphere_bounds() {
+    use super::Perspective;
+    let proj = Projective3::perspective(1.0, crate::PI / 2.0, 1.0, 100.0);
+
+    // In front of the near plane
+    let sphere = Sphere3::new(vec3(0.0, 0.0, -5.0), 1.0);
+    let bounds = project_sphere_bounds(&proj, &sphere).unwrap();
+    let k = 1.0 / (24.0 as Scalar).sqrt();
+    approx::assert_relative_eq!(bounds.min(), vec2(-k, -k), epsilon = 1e-5);
+    approx::assert_relative_eq!(bounds.max(), vec2(k, k), epsilon = 1e-5);
+
+    // Off-center, compared against sampled points on the silhouette
+    let sphere = Sphere3::new(vec3(2.0, -1.0, -6.0), 1.5);
+    let bounds = project_sphere_bounds(&proj, &sphere).unwrap();
+    for i in 0..64 {
+        let angle = i as Scalar / 64.0 * 2.0 * crate::PI;
+        for j in 1..16 {
+            let elev = j as Scalar / 16.0 * crate::PI - crate::PI / 2.0;
+            let dir = vec3(elev.cos() * angle.cos(), elev.cos() * angle.sin(), elev.sin());
+            let ndc = proj * (sphere.center + dir * sphere.radius);
+            assert!(bounds.min().x - 1e-5 <= ndc.x && ndc.x <= bounds.max().x + 1e-5);
+            assert!(bounds.min().y - 1e-5 <= ndc.y && ndc.y <= bounds.max().y + 1e-5);
+        }
+    }
+
+    // Crossing the near plane
+    let sphere = Sphere3::new(vec3(0.0, 0.0, -1.0), 1.0);
+    let bounds = project_sphere_bounds(&proj, &sphere).unwrap();
+    assert!(bounds.contains(vec2(0.0, 0.0)));
+
+    // Behind the near plane
+    let sphere = Sphere3::new(vec3(0.0, 0.0, 1.0), 1.0);
+    assert_eq!(project_sphere_bounds(&proj, &sphere), None);
+}
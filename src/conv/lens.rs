@@ -0,0 +1,206 @@
+//! This module defines lens models, which describe how a camera maps directions in camera space
+//! onto its image plane. These are typically obtained from camera calibration and used along with
+//! [`super::PinholeCamera`].
+//!
+//! Unlike the rest of this crate, lens models follow the conventions used by
+//! [OpenCV](https://docs.opencv.org/4.x/d9/d0c/group__calib3d.html): in camera space, positive X
+//! points right, positive Y points down and positive Z points forward. The "normalized image
+//! plane" is the plane `z = 1`, with the same X and Y axes.
+use crate::{math, vec2, vec3, Scalar, Vector2, Vector3};
+
+/// A model for how a camera maps directions in camera space onto its normalized image plane.
+pub trait LensModel {
+    /// Projects a point in camera space onto the normalized image plane.
+    fn project(&self, point: Vector3) -> Vector2;
+
+    /// Gets the unit direction, in camera space, of the points which project onto the given
+    /// point on the normalized image plane.
+    fn unproject(&self, point: Vector2) -> Vector3;
+}
+
+/// An ideal lens without any distortion.
+#[derive(Default, PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rectilinear;
+
+impl LensModel for Rectilinear {
+    #[inline]
+    fn project(&self, point: Vector3) -> Vector2 {
+        vec2(point.x / point.z, point.y / point.z)
+    }
+
+    #[inline]
+    fn unproject(&self, point: Vector2) -> Vector3 {
+        vec3(point.x, point.y, 1.0).normalize()
+    }
+}
+
+/// The number of iterations used when numerically inverting a distortion model.
+const INVERSE_ITERATIONS: usize = 20;
+
+/// The Brown–Conrady lens model, with radial and tangential distortion. This is the default model
+/// used by OpenCV, with coefficients `(k_1, k_2, p_1, p_2, k_3)`.
+#[derive(Default, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BrownConrady {
+    /// The first radial distortion coefficient.
+    pub k_1: Scalar,
+
+    /// The second radial distortion coefficient.
+    pub k_2: Scalar,
+
+    /// The third radial distortion coefficient.
+    pub k_3: Scalar,
+
+    /// The first tangential distortion coefficient.
+    pub p_1: Scalar,
+
+    /// The second tangential distortion coefficient.
+    pub p_2: Scalar,
+}
+
+impl BrownConrady {
+    /// Applies this distortion to an undistorted point on the normalized image plane.
+    pub fn distort(&self, point: Vector2) -> Vector2 {
+        let (x, y) = (point.x, point.y);
+        let r_2 = x * x + y * y;
+        let radial = 1.0 + r_2 * (self.k_1 + r_2 * (self.k_2 + r_2 * self.k_3));
+        vec2(
+            x * radial + 2.0 * self.p_1 * x * y + self.p_2 * (r_2 + 2.0 * x * x),
+            y * radial + self.p_1 * (r_2 + 2.0 * y * y) + 2.0 * self.p_2 * x * y,
+        )
+    }
+
+    /// Removes this distortion from a distorted point on the normalized image plane.
+    ///
+    /// This is computed iteratively, and is only accurate within the region where the distortion
+    /// is invertible.
+    pub fn undistort(&self, point: Vector2) -> Vector2 {
+        let mut res = point;
+        for _ in 0..INVERSE_ITERATIONS {
+            let (x, y) = (res.x, res.y);
+            let r_2 = x * x + y * y;
+            let radial = 1.0 + r_2 * (self.k_1 + r_2 * (self.k_2 + r_2 * self.k_3));
+            let d_x = 2.0 * self.p_1 * x * y + self.p_2 * (r_2 + 2.0 * x * x);
+            let d_y = self.p_1 * (r_2 + 2.0 * y * y) + 2.0 * self.p_2 * x * y;
+            res = vec2((point.x - d_x) / radial, (point.y - d_y) / radial);
+        }
+        res
+    }
+}
+
+impl LensModel for BrownConrady {
+    #[inline]
+    fn project(&self, point: Vector3) -> Vector2 {
+        self.distort(Rectilinear.project(point))
+    }
+
+    #[inline]
+    fn unproject(&self, point: Vector2) -> Vector3 {
+        Rectilinear.unproject(self.undistort(point))
+    }
+}
+
+/// The Kannala–Brandt lens model for fisheye cameras. This is the model used by OpenCV's `fisheye`
+/// module, with coefficients `(k_1, k_2, k_3, k_4)`.
+///
+/// Unlike [`BrownConrady`], this can represent lenses with a field of view of 180 degrees or more.
+#[derive(Default, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KannalaBrandt {
+    /// The first distortion coefficient.
+    pub k_1: Scalar,
+
+    /// The second distortion coefficient.
+    pub k_2: Scalar,
+
+    /// The third distortion coefficient.
+    pub k_3: Scalar,
+
+    /// The fourth distortion coefficient.
+    pub k_4: Scalar,
+}
+
+impl KannalaBrandt {
+    /// Gets the distorted radius on the normalized image plane for a ray at the given angle,
+    /// in radians, from the optical axis, along with its derivative with respect to the angle.
+    fn theta_d(&self, theta: Scalar) -> (Scalar, Scalar) {
+        let t_2 = theta * theta;
+        let poly = 1.0 + t_2 * (self.k_1 + t_2 * (self.k_2 + t_2 * (self.k_3 + t_2 * self.k_4)));
+        let deriv = 1.0
+            + t_2
+                * (3.0 * self.k_1
+                    + t_2 * (5.0 * self.k_2 + t_2 * (7.0 * self.k_3 + t_2 * 9.0 * self.k_4)));
+        (theta * poly, deriv)
+    }
+}
+
+impl LensModel for KannalaBrandt {
+    fn project(&self, point: Vector3) -> Vector2 {
+        let r = math::sqrt(point.x * point.x + point.y * point.y);
+        if r > 0.0 {
+            let theta = math::atan2(r, point.z);
+            let (theta_d, _) = self.theta_d(theta);
+            vec2(point.x, point.y) * (theta_d / r)
+        } else {
+            vec2(0.0, 0.0)
+        }
+    }
+
+    fn unproject(&self, point: Vector2) -> Vector3 {
+        let theta_d = math::sqrt(point.x * point.x + point.y * point.y);
+        if theta_d > 0.0 {
+            // Solve for theta using Newton's method
+            let mut theta = theta_d;
+            for _ in 0..INVERSE_ITERATIONS {
+                let (value, deriv) = self.theta_d(theta);
+                theta -= (value - theta_d) / deriv;
+            }
+            let (sin, cos) = math::sin_cos(theta);
+            let scale = sin / theta_d;
+            vec3(point.x * scale, point.y * scale, cos)
+        } else {
+            vec3(0.0, 0.0, 1.0)
+        }
+    }
+}
+
+#[test]
+fn test_brown_conrady() {
+    let lens = BrownConrady {
+        k_1: -0.28,
+        k_2: 0.07,
+        k_3: 0.0,
+        p_1: 0.0002,
+        p_2: -0.0001,
+    };
+    let point = vec2(0.3, -0.2);
+    approx::assert_relative_eq!(lens.undistort(lens.distort(point)), point, epsilon = 1e-5);
+    let dir = vec3(0.2, -0.3, 1.0).normalize();
+    approx::assert_relative_eq!(lens.unproject(lens.project(dir)), dir, epsilon = 1e-5);
+}
+
+#[test]
+fn test_kannala_brandt() {
+    let lens = KannalaBrandt {
+        k_1: 0.02,
+        k_2: -0.005,
+        k_3: 0.001,
+        k_4: 0.0,
+    };
+    for dir in [
+        vec3(0.2, -0.3, 1.0),
+        vec3(1.0, 0.5, 0.1),
+        vec3(-1.0, 0.2, -0.3),
+        vec3(0.0, 0.0, 1.0),
+    ] {
+        let dir = dir.normalize();
+        approx::assert_relative_eq!(lens.unproject(lens.project(dir)), dir, epsilon = 1e-5);
+    }
+
+    // Without distortion, the distance from the center is the angle from the optical axis
+    let lens = KannalaBrandt::default();
+    let angle = 1.2 as Scalar;
+    let point = lens.project(vec3(angle.sin(), 0.0, angle.cos()));
+    approx::assert_relative_eq!(point, vec2(angle, 0.0), epsilon = 1e-6);
+}
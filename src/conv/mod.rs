@@ -0,0 +1,829 @@
+//! This module defines helper functions that assume a particular convention for coordinate
+//! systems and projection transforms. If this convention does not fit your needs, you can
+//! simply ignore this module.
+//!
+//! The convention assumes that, for all objects:
+//! * Positive X points to the right
+//! * Positive Y points up
+//! * Negative Z points forward
+//!
+//! This is the convention used by
+//! [Godot](https://docs.godotengine.org/en/stable/tutorials/3d/introduction_to_3d.html#coordinate-system)
+//! and [glTF](https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#view-matrix) cameras (but
+//! not objects, where positive Z points forward).
+//!
+//! Furthermore, "normalized device coordinates", which are the target of all projection transforms
+//! in this module, are in the range \[-1, 1\] × \[-1, 1\] × \[0, 1\] with higher Z values being
+//! *closer* to the camera. This is the convention used by WebGPU, D3D12 and Metal when the
+//! [reversed-Z trick](https://developer.nvidia.com/blog/visualizing-depth-precision/) is applied.
+//!
+//! For applications that need a different convention, [`Convention`] provides equivalents of the
+//! most common helpers, parameterized by the coordinate system and clip-space convention.
+use crate::shape::Ray3;
+use crate::{math, vec3, vec3i, vec4, Affine3, Dir3i, Matrix3, Matrix4, Motion3, Projective3};
+use crate::{Rotation3, Rotation3i, Scalar, Similarity3, Vector2, Vector3, Vector3i};
+use cantor::Finite;
+
+mod bounds;
+mod pinhole;
+mod shadow;
+mod viewport;
+
+pub mod lens;
+
+pub use bounds::{project_box_bounds, project_sphere_bounds};
+pub use pinhole::PinholeCamera;
+pub use shadow::{fit_shadow_projection, frustum_slice_corners, practical_split_depths};
+pub use shadow::ShadowProjection;
+pub use viewport::Viewport;
+
+/// A transformation which supports the [`LookTowards::look_towards`] method.
+pub trait LookTowards: Sized {
+    /// Constructs an object-to-world transform which rotates an object to face the given
+    /// direction.
+    ///
+    /// The object's up axis will be as close as possible to positive Y. See
+    /// [`LookTowards::look_towards_with_up`] for the behavior when `dir` is vertical.
+    #[inline]
+    fn look_towards(dir: Vector3) -> Self {
+        Self::look_towards_with_up(dir, vec3(0.0, 1.0, 0.0))
+    }
+
+    /// Constructs an object-to-world transform which rotates an object to face the given
+    /// direction, with its up axis as close as possible to the given `up` vector.
+    ///
+    /// If `dir` is parallel (or very nearly parallel) to `up`, the `up` vector is replaced by
+    /// the coordinate axis which is most perpendicular to `dir`, preferring Z, then Y, then X
+    /// in case of a tie. For example, when `up` is positive Y and `dir` points straight up or
+    /// down, the object's up axis will be along positive Z.
+    fn look_towards_with_up(dir: Vector3, up: Vector3) -> Self;
+}
+
+impl LookTowards for Matrix3 {
+    fn look_towards_with_up(dir: Vector3, up: Vector3) -> Self {
+        let z = -dir.normalize();
+        let mut x = up.cross(&z);
+        if x.norm_squared() <= 1e-8 * up.norm_squared() {
+            let abs = vec3(z.x.abs(), z.y.abs(), z.z.abs());
+            let fallback_up = if abs.x < abs.y && abs.x < abs.z {
+                vec3(1.0, 0.0, 0.0)
+            } else if abs.y < abs.z {
+                vec3(0.0, 1.0, 0.0)
+            } else {
+                vec3(0.0, 0.0, 1.0)
+            };
+            x = fallback_up.cross(&z);
+        }
+        let x = x.normalize();
+        let y = z.cross(&x);
+        Self { x, y, z }
+    }
+}
+
+impl LookTowards for Rotation3 {
+    #[inline]
+    fn look_towards_with_up(dir: Vector3, up: Vector3) -> Self {
+        Self::from_matrix(Matrix3::look_towards_with_up(dir, up))
+    }
+}
+
+#[test]
+fn test_look_towards_pole() {
+    for dir in [vec3(0.0, 1.0, 0.0), vec3(0.0, -2.0, 0.0), vec3(1e-6, 1.0, 0.0)] {
+        let mat = Matrix3::look_towards(dir);
+        approx::assert_relative_eq!(mat * vec3(0.0, 0.0, -1.0), dir.normalize(), epsilon = 1e-5);
+        approx::assert_relative_eq!(mat.x.dot(&mat.y), 0.0, epsilon = 1e-5);
+        approx::assert_relative_eq!(mat.x.norm(), 1.0, epsilon = 1e-5);
+    }
+    let mat = Matrix3::look_towards(vec3(0.0, 1.0, 0.0));
+    approx::assert_relative_eq!(mat.y, vec3(0.0, 0.0, 1.0), epsilon = 1e-5);
+}
+
+#[test]
+fn test_look_towards_with_up() {
+    let mat = Matrix3::look_towards_with_up(vec3(1.0, 0.0, 0.0), vec3(0.0, 0.0, 1.0));
+    approx::assert_relative_eq!(mat.y, vec3(0.0, 0.0, 1.0));
+    approx::assert_relative_eq!(mat.x, vec3(0.0, -1.0, 0.0));
+}
+
+/// A transformation which supports the [`LookAt::look_at`] method.
+pub trait LookAt: Sized {
+    /// Constructs an object-to-world transform which positions the object at the given position
+    /// and rotates it to face the given direction.
+    ///
+    /// Note that this needs to be inverted to get a world-to-view transform which is more
+    /// useful for cameras. See [`ViewLookAt`].
+    #[inline]
+    fn look_at(pos: Vector3, target: Vector3) -> Self {
+        Self::look_at_with_up(pos, target, vec3(0.0, 1.0, 0.0))
+    }
+
+    /// Constructs an object-to-world transform which positions the object at the given position
+    /// and rotates it to face the given direction, with its up axis as close as possible to the
+    /// given `up` vector.
+    ///
+    /// See [`LookTowards::look_towards_with_up`] for the behavior when the direction is parallel
+    /// to `up`.
+    fn look_at_with_up(pos: Vector3, target: Vector3, up: Vector3) -> Self;
+}
+
+impl LookAt for Motion3 {
+    #[inline]
+    fn look_at_with_up(pos: Vector3, target: Vector3, up: Vector3) -> Self {
+        Motion3 {
+            rotation: Rotation3::look_towards_with_up(target - pos, up),
+            offset: pos,
+        }
+    }
+}
+
+impl LookAt for Similarity3 {
+    #[inline]
+    fn look_at_with_up(pos: Vector3, target: Vector3, up: Vector3) -> Self {
+        Motion3::look_at_with_up(pos, target, up).into()
+    }
+}
+
+impl LookAt for Affine3 {
+    #[inline]
+    fn look_at_with_up(pos: Vector3, target: Vector3, up: Vector3) -> Self {
+        Affine3 {
+            linear: Matrix3::look_towards_with_up(target - pos, up),
+            offset: pos,
+        }
+    }
+}
+
+impl LookAt for Projective3 {
+    #[inline]
+    fn look_at_with_up(pos: Vector3, target: Vector3, up: Vector3) -> Self {
+        Affine3::look_at_with_up(pos, target, up).into()
+    }
+}
+
+#[test]
+fn test_look_at() {
+    use core::f32::consts::SQRT_2;
+    let trans = Affine3::look_at(vec3(1.0, 1.0, 1.0), vec3(1.0, 0.0, 0.0));
+    approx::assert_relative_eq!(trans * vec3(1.0, 0.0, -SQRT_2), vec3(2.0, 0.0, 0.0));
+}
+
+/// A transformation which supports the [`ViewLookAt::view_look_at`] method.
+pub trait ViewLookAt: Sized {
+    /// Constructs a world-to-view transform for a camera at the given position, facing the
+    /// given target. This is the inverse of [`LookAt::look_at`].
+    #[inline]
+    fn view_look_at(pos: Vector3, target: Vector3) -> Self {
+        Self::view_look_at_with_up(pos, target, vec3(0.0, 1.0, 0.0))
+    }
+
+    /// Constructs a world-to-view transform for a camera at the given position, facing the
+    /// given target, with its up axis as close as possible to the given `up` vector. This is the
+    /// inverse of [`LookAt::look_at_with_up`].
+    fn view_look_at_with_up(pos: Vector3, target: Vector3, up: Vector3) -> Self;
+}
+
+impl ViewLookAt for Motion3 {
+    #[inline]
+    fn view_look_at_with_up(pos: Vector3, target: Vector3, up: Vector3) -> Self {
+        Motion3::look_at_with_up(pos, target, up).inverse()
+    }
+}
+
+impl ViewLookAt for Similarity3 {
+    #[inline]
+    fn view_look_at_with_up(pos: Vector3, target: Vector3, up: Vector3) -> Self {
+        Motion3::view_look_at_with_up(pos, target, up).into()
+    }
+}
+
+impl ViewLookAt for Affine3 {
+    #[inline]
+    fn view_look_at_with_up(pos: Vector3, target: Vector3, up: Vector3) -> Self {
+        Motion3::view_look_at_with_up(pos, target, up).into()
+    }
+}
+
+impl ViewLookAt for Projective3 {
+    #[inline]
+    fn view_look_at_with_up(pos: Vector3, target: Vector3, up: Vector3) -> Self {
+        Motion3::view_look_at_with_up(pos, target, up).into()
+    }
+}
+
+#[test]
+fn test_view_look_at() {
+    let pos = vec3(1.0, 2.0, 3.0);
+    let target = vec3(2.0, 2.0, 1.0);
+    let dist = (target - pos).norm();
+    let view = Projective3::view_look_at(pos, target);
+    approx::assert_relative_eq!(view * target, vec3(0.0, 0.0, -dist), epsilon = 1e-5);
+    let view = Similarity3::view_look_at_with_up(pos, vec3(1.0, 5.0, 3.0), vec3(1.0, 0.0, 0.0));
+    approx::assert_relative_eq!(view * vec3(1.0, 5.0, 3.0), vec3(0.0, 0.0, -3.0), epsilon = 1e-5);
+    approx::assert_relative_eq!(view * vec3(1.0, 3.0, 3.0), vec3(0.0, 0.0, -1.0), epsilon = 1e-5);
+    approx::assert_relative_eq!(view * vec3(2.0, 2.0, 3.0), vec3(0.0, 1.0, 0.0), epsilon = 1e-5);
+}
+
+/// A transformation which supports constructing perspective projection transforms.
+pub trait Perspective {
+    /// Constructs a perspective transform.
+    fn perspective(aspect_ratio: Scalar, fov_y: Scalar, near_z: Scalar, far_z: Scalar) -> Self;
+}
+
+impl Perspective for Projective3 {
+    fn perspective(aspect_ratio: Scalar, fov_y: Scalar, near_z: Scalar, far_z: Scalar) -> Self {
+        let y_y = 1.0 / math::tan(fov_y / 2.0);
+        let x_x = y_y / aspect_ratio;
+        let (z_z, w_z) = perspective_depth_terms(near_z, far_z);
+        Self::new(Matrix4 {
+            x: vec4(x_x, 0.0, 0.0, 0.0),
+            y: vec4(0.0, y_y, 0.0, 0.0),
+            z: vec4(0.0, 0.0, z_z, -1.0),
+            w: vec4(0.0, 0.0, w_z, 0.0),
+        })
+    }
+}
+
+/// A transformation which supports constructing perspective projection transforms for possibly
+/// asymmetric (off-axis) frustums.
+pub trait OffAxisPerspective {
+    /// Constructs a perspective transform for a possibly asymmetric (off-axis) frustum.
+    ///
+    /// `left`, `right`, `bottom` and `top` give the extents of the frustum on the near plane, in
+    /// view space. `far_z` may be [`Scalar::INFINITY`].
+    fn frustum(
+        left: Scalar,
+        right: Scalar,
+        bottom: Scalar,
+        top: Scalar,
+        near_z: Scalar,
+        far_z: Scalar,
+    ) -> Self;
+}
+
+impl OffAxisPerspective for Projective3 {
+    fn frustum(
+        left: Scalar,
+        right: Scalar,
+        bottom: Scalar,
+        top: Scalar,
+        near_z: Scalar,
+        far_z: Scalar,
+    ) -> Self {
+        let x_x = 2.0 * near_z / (right - left);
+        let y_y = 2.0 * near_z / (top - bottom);
+        let z_x = (right + left) / (right - left);
+        let z_y = (top + bottom) / (top - bottom);
+        let (z_z, w_z) = perspective_depth_terms(near_z, far_z);
+        Self::new(Matrix4 {
+            x: vec4(x_x, 0.0, 0.0, 0.0),
+            y: vec4(0.0, y_y, 0.0, 0.0),
+            z: vec4(z_x, z_y, z_z, -1.0),
+            w: vec4(0.0, 0.0, w_z, 0.0),
+        })
+    }
+}
+
+/// Gets the `z.z` and `w.z` entries of a reversed-Z perspective projection matrix.
+fn perspective_depth_terms(near_z: Scalar, far_z: Scalar) -> (Scalar, Scalar) {
+    if far_z == Scalar::INFINITY {
+        (0.0, near_z)
+    } else {
+        let z_z = near_z / (far_z - near_z);
+        (z_z, far_z * z_z)
+    }
+}
+
+#[test]
+fn test_perspective() {
+    let proj = Projective3::perspective(2.0, crate::PI / 2.0, 1.0, 5.0);
+    approx::assert_relative_eq!(proj * vec3(-2.0, -1.0, -1.0), vec3(-1.0, -1.0, 1.0));
+    approx::assert_relative_eq!(proj * vec3(2.0, -1.0, -1.0), vec3(1.0, -1.0, 1.0));
+    approx::assert_relative_eq!(proj * vec3(10.0, 5.0, -5.0), vec3(1.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_frustum() {
+    let proj = Projective3::frustum(-1.0, 3.0, -2.0, 1.0, 1.0, 5.0);
+    approx::assert_relative_eq!(proj * vec3(-1.0, -2.0, -1.0), vec3(-1.0, -1.0, 1.0));
+    approx::assert_relative_eq!(proj * vec3(3.0, 1.0, -1.0), vec3(1.0, 1.0, 1.0));
+    approx::assert_relative_eq!(proj * vec3(15.0, 5.0, -5.0), vec3(1.0, 1.0, 0.0));
+    let sym = Projective3::frustum(-2.0, 2.0, -1.0, 1.0, 1.0, 5.0);
+    let proj = Projective3::perspective(2.0, crate::PI / 2.0, 1.0, 5.0);
+    let x = vec3(0.5, 0.3, -2.0);
+    approx::assert_relative_eq!(sym * x, proj * x);
+}
+
+/// A transformation which supports constructing orthographic projection transforms.
+pub trait Orthographic {
+    /// Constructs an orthographic transform which maps the given view-space box to normalized
+    /// device coordinates.
+    ///
+    /// `left`, `right`, `bottom` and `top` give the extents of the box along the X and Y axes.
+    /// `near_z` and `far_z` are the distances to the near and far planes along the view axis.
+    fn orthographic(
+        left: Scalar,
+        right: Scalar,
+        bottom: Scalar,
+        top: Scalar,
+        near_z: Scalar,
+        far_z: Scalar,
+    ) -> Self;
+}
+
+impl Orthographic for Projective3 {
+    fn orthographic(
+        left: Scalar,
+        right: Scalar,
+        bottom: Scalar,
+        top: Scalar,
+        near_z: Scalar,
+        far_z: Scalar,
+    ) -> Self {
+        let x_x = 2.0 / (right - left);
+        let y_y = 2.0 / (top - bottom);
+        let z_z = 1.0 / (far_z - near_z);
+        Self::new(Matrix4 {
+            x: vec4(x_x, 0.0, 0.0, 0.0),
+            y: vec4(0.0, y_y, 0.0, 0.0),
+            z: vec4(0.0, 0.0, z_z, 0.0),
+            w: vec4(
+                -(right + left) / (right - left),
+                -(top + bottom) / (top - bottom),
+                far_z * z_z,
+                1.0,
+            ),
+        })
+    }
+}
+
+#[test]
+fn test_orthographic() {
+    let proj = Projective3::orthographic(-1.0, 3.0, -2.0, 1.0, 1.0, 5.0);
+    approx::assert_relative_eq!(proj * vec3(-1.0, -2.0, -1.0), vec3(-1.0, -1.0, 1.0));
+    approx::assert_relative_eq!(proj * vec3(3.0, 1.0, -5.0), vec3(1.0, 1.0, 0.0));
+    approx::assert_relative_eq!(proj * vec3(1.0, -0.5, -3.0), vec3(0.0, 0.0, 0.5));
+}
+
+/// Applies a translation, in normalized device coordinates, to the output of the given
+/// projection. This can be used to apply sub-pixel jitter for temporal anti-aliasing.
+///
+/// A jitter of one pixel corresponds to an offset of `2 / width` along X, or `2 / height` along
+/// Y.
+#[inline]
+pub fn jitter(proj: Projective3, offset: Vector2) -> Projective3 {
+    Affine3::translate(vec3(offset.x, offset.y, 0.0)) * proj
+}
+
+/// Gets the `index`th sub-pixel offset in the (2, 3) Halton sequence, which is commonly used for
+/// temporal anti-aliasing. Each component is in the range \[-0.5, 0.5\), in pixels.
+///
+/// Callers typically cycle through a fixed number (e.g. 8 or 16) of offsets.
+pub fn halton_jitter(index: u32) -> Vector2 {
+    fn halton(mut index: u32, base: u32) -> Scalar {
+        let mut f = 1.0;
+        let mut r = 0.0;
+        let inv_base = 1.0 / base as Scalar;
+        while index > 0 {
+            f *= inv_base;
+            r += f * (index % base) as Scalar;
+            index /= base;
+        }
+        r
+    }
+    let index = index.wrapping_add(1);
+    crate::vec2(halton(index, 2) - 0.5, halton(index, 3) - 0.5)
+}
+
+#[test]
+fn test_jitter() {
+    let proj = Projective3::perspective(2.0, crate::PI / 2.0, 1.0, 5.0);
+    let offset = crate::vec2(0.01, -0.02);
+    let jittered = jitter(proj, offset);
+    let x = vec3(0.5, 0.3, -2.0);
+    approx::assert_relative_eq!(jittered * x, proj * x + vec3(offset.x, offset.y, 0.0));
+    approx::assert_relative_eq!(halton_jitter(0), crate::vec2(0.0, -1.0 / 6.0));
+    approx::assert_relative_eq!(halton_jitter(1), crate::vec2(-0.25, 1.0 / 6.0));
+}
+
+/// A transformation which supports recovering the parameters given to
+/// [`Perspective::perspective`].
+///
+/// The results are unspecified if the transform was not constructed using
+/// [`Perspective::perspective`]. In particular, this doesn't support projections built by
+/// [`Convention::perspective`] for conventions with a different depth range.
+pub trait PerspectiveParams {
+    /// Gets the aspect ratio (width over height) of the projection.
+    fn aspect_ratio(&self) -> Scalar;
+
+    /// Gets the vertical field of view of the projection, in radians.
+    fn fov_y(&self) -> Scalar;
+
+    /// Gets the distance to the near plane of the projection.
+    fn near_z(&self) -> Scalar;
+
+    /// Gets the distance to the far plane of the projection. This may be [`Scalar::INFINITY`].
+    fn far_z(&self) -> Scalar;
+}
+
+impl PerspectiveParams for Projective3 {
+    #[inline]
+    fn aspect_ratio(&self) -> Scalar {
+        let m = self.as_matrix();
+        m.y.y / m.x.x
+    }
+
+    #[inline]
+    fn fov_y(&self) -> Scalar {
+        2.0 * math::atan(1.0 / self.as_matrix().y.y)
+    }
+
+    #[inline]
+    fn near_z(&self) -> Scalar {
+        let m = self.as_matrix();
+        m.w.z / (1.0 + m.z.z)
+    }
+
+    #[inline]
+    fn far_z(&self) -> Scalar {
+        let m = self.as_matrix();
+        if m.z.z == 0.0 {
+            Scalar::INFINITY
+        } else {
+            m.w.z / m.z.z
+        }
+    }
+}
+
+#[test]
+fn test_perspective_params() {
+    for far_z in [5.0, Scalar::INFINITY] {
+        let proj = Projective3::perspective(1.5, 1.2, 0.1, far_z);
+        approx::assert_relative_eq!(proj.aspect_ratio(), 1.5);
+        approx::assert_relative_eq!(proj.fov_y(), 1.2);
+        approx::assert_relative_eq!(proj.near_z(), 0.1);
+        approx::assert_relative_eq!(proj.far_z(), far_z, max_relative = 1e-5);
+    }
+}
+
+/// Converts a depth value in normalized device coordinates, as produced by
+/// [`Perspective::perspective`], into a distance from the camera along the view axis.
+///
+/// `far_z` may be [`Scalar::INFINITY`]. `ndc_z` must follow the reversed-Z \[0, 1\] depth range
+/// assumed by this module.
+#[inline]
+pub fn view_depth_from_ndc_z(near_z: Scalar, far_z: Scalar, ndc_z: Scalar) -> Scalar {
+    if far_z == Scalar::INFINITY {
+        near_z / ndc_z
+    } else {
+        far_z * near_z / (ndc_z * (far_z - near_z) + near_z)
+    }
+}
+
+/// Converts a depth value in normalized device coordinates, as produced by
+/// [`Perspective::perspective`], into a linear depth value, which is the distance from the camera
+/// along the view axis divided by `far_z`.
+///
+/// The result is `1` on the far plane and `near_z / far_z` on the near plane. For an infinite far
+/// plane, this is always `0`; use [`view_depth_from_ndc_z`] instead. As with
+/// [`view_depth_from_ndc_z`], `ndc_z` must follow the reversed-Z \[0, 1\] depth range.
+#[inline]
+pub fn linearize_depth(near_z: Scalar, far_z: Scalar, ndc_z: Scalar) -> Scalar {
+    view_depth_from_ndc_z(near_z, far_z, ndc_z) / far_z
+}
+
+#[test]
+fn test_view_depth_from_ndc_z() {
+    for far_z in [5.0, Scalar::INFINITY] {
+        let proj = Projective3::perspective(1.0, 1.0, 0.5, far_z);
+        for depth in [0.5, 1.0, 3.0, 5.0] {
+            let ndc_z = (proj * vec3(0.0, 0.0, -depth)).z;
+            approx::assert_relative_eq!(
+                view_depth_from_ndc_z(0.5, far_z, ndc_z),
+                depth,
+                max_relative = 1e-5
+            );
+        }
+    }
+    approx::assert_relative_eq!(linearize_depth(0.5, 5.0, 0.0), 1.0);
+    approx::assert_relative_eq!(linearize_depth(0.5, 5.0, 1.0), 0.1);
+}
+
+/// Gets the ray consisting of all points which are projected onto the given point in normalized
+/// device coordinates by the given projection.
+///
+/// The projection will typically be a perspective transform composed with a world-to-view
+/// transform, in which case the returned ray is in world space. The ray starts on the near plane
+/// and its direction is a unit vector pointing away from the camera. This works for both finite
+/// and infinite far planes.
+///
+/// This assumes the projection maps the near plane to Z = 1 and the far plane to Z = 0, as with
+/// [`Perspective::perspective`]. Use [`Convention::ndc_to_ray`] for other depth ranges.
+pub fn ndc_to_ray(proj: &Projective3, ndc: Vector2) -> Ray3 {
+    let inv = proj.inverse();
+    let near = inv * vec3(ndc.x, ndc.y, 1.0);
+    let far = inv * vec4(ndc.x, ndc.y, 0.0, 1.0);
+    let dir = vec3(far.x, far.y, far.z) - near * far.w;
+    Ray3::new(near, dir.normalize())
+}
+
+#[test]
+fn test_ndc_to_ray() {
+    let view = Motion3::look_at(vec3(1.0, 2.0, 3.0), vec3(0.0, 0.0, 0.0));
+    for far_z in [10.0, Scalar::INFINITY] {
+        let proj = Projective3::perspective(1.5, 1.0, 0.5, far_z) * view.inverse();
+        let target = vec3(0.2, -0.3, 0.1);
+        let ndc = proj * target;
+        let ray = ndc_to_ray(&proj, crate::vec2(ndc.x, ndc.y));
+        approx::assert_relative_eq!(proj * ray.origin, vec3(ndc.x, ndc.y, 1.0), epsilon = 1e-4);
+        approx::assert_relative_eq!(ray.dir, (target - ray.origin).normalize(), epsilon = 1e-4);
+    }
+}
+
+/// Describes a coordinate system and clip-space convention, for use when the default convention
+/// assumed by this module is not appropriate.
+///
+/// The directional components (`up`, `forward` and `handedness`) describe the local axes of
+/// objects and cameras. The remaining components describe the normalized device coordinates
+/// produced by [`Convention::perspective`].
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Convention {
+    /// The axis which points up.
+    pub up: Dir3i,
+
+    /// The axis which points forward. This must be perpendicular to `up`.
+    pub forward: Dir3i,
+
+    /// Determines which axis points to the right, given `up` and `forward`.
+    pub handedness: Handedness,
+
+    /// The range of depth values in normalized device coordinates.
+    pub depth_range: DepthRange,
+
+    /// If true, higher depth values in normalized device coordinates are *closer* to the camera.
+    pub reversed_z: bool,
+}
+
+/// Identifies the handedness of a coordinate system.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Handedness {
+    /// Right is `forward × up`.
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
+    Right,
+
+    /// Right is `up × forward`.
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
+    Left,
+}
+
+/// Identifies the range of depth values in normalized device coordinates.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DepthRange {
+    /// Depth values are in the range \[0, 1\], as in WebGPU, Vulkan, D3D12 and Metal.
+    #[cfg_attr(feature = "serde", serde(rename = "zero_to_one"))]
+    ZeroToOne,
+
+    /// Depth values are in the range \[-1, 1\], as in OpenGL.
+    #[cfg_attr(feature = "serde", serde(rename = "neg_one_to_one"))]
+    NegOneToOne,
+}
+
+impl Convention {
+    /// The convention assumed by the rest of this module.
+    pub const DEFAULT: Convention = Convention {
+        up: Dir3i::Yp,
+        forward: Dir3i::Zn,
+        handedness: Handedness::Right,
+        depth_range: DepthRange::ZeroToOne,
+        reversed_z: true,
+    };
+
+    /// The traditional OpenGL convention, with Y up, negative Z forward and depth values in
+    /// \[-1, 1\] without reversed-Z.
+    pub const OPENGL: Convention = Convention {
+        up: Dir3i::Yp,
+        forward: Dir3i::Zn,
+        handedness: Handedness::Right,
+        depth_range: DepthRange::NegOneToOne,
+        reversed_z: false,
+    };
+
+    /// The convention used by Blender, with Z up and objects facing negative Y.
+    pub const BLENDER: Convention = Convention {
+        up: Dir3i::Zp,
+        forward: Dir3i::Yn,
+        handedness: Handedness::Right,
+        depth_range: DepthRange::NegOneToOne,
+        reversed_z: false,
+    };
+
+    /// The convention used by glTF objects, with Y up and objects facing positive Z.
+    ///
+    /// glTF cameras instead look along negative Z, so cameras constructed with this convention
+    /// (e.g. by [`Convention::look_at`]) would face backwards. Use [`Convention::GLTF_CAMERA`] for
+    /// cameras.
+    pub const GLTF_OBJECT: Convention = Convention {
+        up: Dir3i::Yp,
+        forward: Dir3i::Zp,
+        handedness: Handedness::Right,
+        depth_range: DepthRange::ZeroToOne,
+        reversed_z: true,
+    };
+
+    /// The convention used by glTF cameras, with Y up and cameras looking along negative Z. Use
+    /// [`Convention::GLTF_OBJECT`] for other objects.
+    pub const GLTF_CAMERA: Convention = Convention {
+        forward: Dir3i::Zn,
+        ..Convention::GLTF_OBJECT
+    };
+
+    /// Gets the axis which points to the right.
+    pub fn right(&self) -> Dir3i {
+        let (a, b) = match self.handedness {
+            Handedness::Right => (self.forward, self.up),
+            Handedness::Left => (self.up, self.forward),
+        };
+        let a = Vector3i::from(a);
+        let b = Vector3i::from(b);
+        let right = vec3i(
+            a.y * b.z - a.z * b.y,
+            a.z * b.x - a.x * b.z,
+            a.x * b.y - a.y * b.x,
+        );
+        Dir3i::iter()
+            .find(|dir| Vector3i::from(*dir) == right)
+            .expect("up and forward must be perpendicular")
+    }
+
+    /// Gets the rotation which converts coordinates in the `from` convention into coordinates in
+    /// the `to` convention, or returns [`None`] if the conventions have different handedness
+    /// (in which case the conversion would be a reflection).
+    ///
+    /// Only the directional components of the conventions are considered.
+    pub fn basis_change(from: &Convention, to: &Convention) -> Option<Rotation3i> {
+        if from.handedness != to.handedness {
+            return None;
+        }
+        Rotation3i::iter().find(|rot| {
+            *rot * Vector3i::from(from.up) == Vector3i::from(to.up)
+                && *rot * Vector3i::from(from.forward) == Vector3i::from(to.forward)
+        })
+    }
+
+    /// Gets the linear transform which maps the axes of the [`Convention::DEFAULT`] convention
+    /// onto the corresponding axes of this convention.
+    fn from_default(&self) -> Matrix3 {
+        Matrix3 {
+            x: self.right().into(),
+            y: self.up.into(),
+            z: -Vector3::from(self.forward),
+        }
+    }
+
+    /// Constructs an object-to-world rotation which rotates an object to face the given
+    /// direction, keeping its up axis as close as possible to the world up axis.
+    ///
+    /// This is the equivalent of [`LookTowards::look_towards`] for this convention.
+    pub fn look_towards(&self, dir: Vector3) -> Rotation3 {
+        let from_default = self.from_default();
+        let to_default = from_default.inverse();
+        let rot = Matrix3::look_towards(to_default * dir);
+        Rotation3::from_matrix(from_default * rot * to_default)
+    }
+
+    /// Constructs an object-to-world transform which positions the object at the given position
+    /// and rotates it to face the given target.
+    ///
+    /// This is the equivalent of [`LookAt::look_at`] for this convention.
+    pub fn look_at(&self, pos: Vector3, target: Vector3) -> Motion3 {
+        Motion3 {
+            rotation: self.look_towards(target - pos),
+            offset: pos,
+        }
+    }
+
+    /// Constructs a perspective transform which maps view space in this convention to normalized
+    /// device coordinates in this convention.
+    ///
+    /// This is the equivalent of [`Perspective::perspective`] for this convention.
+    pub fn perspective(
+        &self,
+        aspect_ratio: Scalar,
+        fov_y: Scalar,
+        near_z: Scalar,
+        far_z: Scalar,
+    ) -> Projective3 {
+        let proj = Projective3::perspective(aspect_ratio, fov_y, near_z, far_z);
+        let to_default = Affine3 {
+            linear: self.from_default().inverse(),
+            offset: vec3(0.0, 0.0, 0.0),
+        };
+        self.depth_from_default() * proj * to_default
+    }
+
+    /// Gets the transform which maps normalized device coordinates in the
+    /// [`Convention::DEFAULT`] convention onto normalized device coordinates in this convention.
+    fn depth_from_default(&self) -> Affine3 {
+        let (z_scale, z_offset) = match (self.depth_range, self.reversed_z) {
+            (DepthRange::ZeroToOne, true) => (1.0, 0.0),
+            (DepthRange::ZeroToOne, false) => (-1.0, 1.0),
+            (DepthRange::NegOneToOne, true) => (2.0, -1.0),
+            (DepthRange::NegOneToOne, false) => (-2.0, 1.0),
+        };
+        Affine3 {
+            linear: Matrix3 {
+                x: vec3(1.0, 0.0, 0.0),
+                y: vec3(0.0, 1.0, 0.0),
+                z: vec3(0.0, 0.0, z_scale),
+            },
+            offset: vec3(0.0, 0.0, z_offset),
+        }
+    }
+
+    /// Gets the ray consisting of all points which are projected onto the given point in
+    /// normalized device coordinates by the given projection, which targets normalized device
+    /// coordinates in this convention.
+    ///
+    /// This is the equivalent of [`ndc_to_ray`] for this convention.
+    pub fn ndc_to_ray(&self, proj: &Projective3, ndc: Vector2) -> Ray3 {
+        ndc_to_ray(&(self.depth_from_default().inverse() * *proj), ndc)
+    }
+}
+
+impl Default for Convention {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[test]
+fn test_convention_right() {
+    assert_eq!(Convention::DEFAULT.right(), Dir3i::Xp);
+    assert_eq!(Convention::BLENDER.right(), Dir3i::Xn);
+    assert_eq!(Convention::GLTF_OBJECT.right(), Dir3i::Xn);
+    assert_eq!(Convention::GLTF_CAMERA.right(), Dir3i::Xp);
+    let d3d = Convention {
+        forward: Dir3i::Zp,
+        handedness: Handedness::Left,
+        ..Convention::DEFAULT
+    };
+    assert_eq!(d3d.right(), Dir3i::Xp);
+}
+
+#[test]
+fn test_convention_basis_change() {
+    let rot = Convention::basis_change(&Convention::BLENDER, &Convention::GLTF_OBJECT).unwrap();
+    assert_eq!(rot * vec3i(1, 2, 3), vec3i(1, 3, -2));
+    let d3d = Convention {
+        handedness: Handedness::Left,
+        ..Convention::DEFAULT
+    };
+    assert_eq!(Convention::basis_change(&Convention::DEFAULT, &d3d), None);
+}
+
+#[test]
+fn test_convention_look_at() {
+    let pos = vec3(1.0, 2.0, 3.0);
+    let target = vec3(-1.0, 0.5, 2.0);
+    let default = Convention::DEFAULT.look_at(pos, target);
+    let expected = Rotation3::look_towards(target - pos);
+    approx::assert_relative_eq!(default.rotation, expected, epsilon = 1e-5);
+    let trans = Convention::BLENDER.look_at(pos, target);
+    let forward = trans.rotation * Vector3::from(Dir3i::Yn);
+    let up = trans.rotation * Vector3::from(Dir3i::Zp);
+    approx::assert_relative_eq!(forward, (target - pos).normalize(), epsilon = 1e-5);
+    assert!(up.z > 0.0);
+    approx::assert_relative_eq!(up.dot(&forward), 0.0, epsilon = 1e-5);
+}
+
+#[test]
+fn test_convention_ndc_to_ray() {
+    let view = Motion3::look_at(vec3(1.0, 2.0, 3.0), vec3(0.0, 0.0, 0.0));
+    for conv in [Convention::DEFAULT, Convention::OPENGL] {
+        let proj = conv.perspective(1.5, 1.0, 0.5, 10.0) * view.inverse();
+        let target = vec3(0.2, -0.3, 0.1);
+        let ndc = proj * target;
+        let ray = conv.ndc_to_ray(&proj, crate::vec2(ndc.x, ndc.y));
+        approx::assert_relative_eq!((view.inverse() * ray.origin).z, -0.5, epsilon = 1e-4);
+        approx::assert_relative_eq!(ray.dir, (target - ray.origin).normalize(), epsilon = 1e-4);
+    }
+}
+
+#[test]
+fn test_convention_perspective() {
+    let proj = Projective3::perspective(2.0, crate::PI / 2.0, 1.0, 5.0);
+    let gl = Convention::OPENGL.perspective(2.0, crate::PI / 2.0, 1.0, 5.0);
+    let x = vec3(0.5, 0.3, -2.0);
+    let ndc = proj * x;
+    approx::assert_relative_eq!(gl * x, vec3(ndc.x, ndc.y, 1.0 - 2.0 * ndc.z), epsilon = 1e-5);
+    let z_up = Convention {
+        up: Dir3i::Zp,
+        forward: Dir3i::Yp,
+        ..Convention::DEFAULT
+    };
+    let z_up_proj = z_up.perspective(2.0, crate::PI / 2.0, 1.0, 5.0);
+    approx::assert_relative_eq!(z_up_proj * vec3(0.5, 2.0, 0.3), ndc, epsilon = 1e-5);
+}
@@ -0,0 +1,131 @@
+use super::perspective_depth_terms;
+use super::lens::LensModel;
+use crate::{vec2, vec3, vec4, Matrix4, Projective3, Scalar, Vector2, Vector3};
+
+/// The intrinsic parameters of a pinhole camera, as typically obtained from camera calibration.
+///
+/// These follow the conventions used by OpenCV: pixel coordinates have their origin at the center
+/// of the top-left pixel, with positive X pointing right and positive Y pointing down. Note that
+/// this differs from [`super::Viewport`], where the origin is at the top-left corner of the
+/// top-left pixel.
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PinholeCamera {
+    /// The horizontal focal length, in pixels.
+    pub f_x: Scalar,
+
+    /// The vertical focal length, in pixels.
+    pub f_y: Scalar,
+
+    /// The X coordinate of the principal point, in pixels.
+    pub c_x: Scalar,
+
+    /// The Y coordinate of the principal point, in pixels.
+    pub c_y: Scalar,
+}
+
+impl PinholeCamera {
+    /// Constructs a [`PinholeCamera`] from its focal lengths and principal point, in pixels.
+    #[inline]
+    pub const fn new(f_x: Scalar, f_y: Scalar, c_x: Scalar, c_y: Scalar) -> Self {
+        Self { f_x, f_y, c_x, c_y }
+    }
+
+    /// Constructs the view-to-NDC perspective projection which is equivalent to this camera, for
+    /// an image of the given size in pixels. `far_z` may be [`Scalar::INFINITY`].
+    pub fn to_projective(&self, size: Vector2, near_z: Scalar, far_z: Scalar) -> Projective3 {
+        let x_x = 2.0 * self.f_x / size.x;
+        let y_y = 2.0 * self.f_y / size.y;
+        let z_x = 1.0 - 2.0 * (self.c_x + 0.5) / size.x;
+        let z_y = 2.0 * (self.c_y + 0.5) / size.y - 1.0;
+        let (z_z, w_z) = perspective_depth_terms(near_z, far_z);
+        Projective3::new(Matrix4 {
+            x: vec4(x_x, 0.0, 0.0, 0.0),
+            y: vec4(0.0, y_y, 0.0, 0.0),
+            z: vec4(z_x, z_y, z_z, -1.0),
+            w: vec4(0.0, 0.0, w_z, 0.0),
+        })
+    }
+
+    /// Gets the intrinsic parameters of a view-to-NDC perspective projection (such as one
+    /// constructed by [`super::Perspective`]), for an image of the given size in pixels.
+    pub fn from_projective(proj: &Projective3, size: Vector2) -> Self {
+        let m = proj.as_matrix();
+        Self {
+            f_x: m.x.x * size.x / 2.0,
+            f_y: m.y.y * size.y / 2.0,
+            c_x: (1.0 - m.z.x) * size.x / 2.0 - 0.5,
+            c_y: (1.0 + m.z.y) * size.y / 2.0 - 0.5,
+        }
+    }
+
+    /// Projects a point in view space onto the image, in pixels, using the given lens model.
+    pub fn project(&self, lens: &impl LensModel, point: Vector3) -> Vector2 {
+        let norm = lens.project(vec3(point.x, -point.y, -point.z));
+        vec2(self.f_x * norm.x + self.c_x, self.f_y * norm.y + self.c_y)
+    }
+
+    /// Gets the unit direction, in view space, of the points which project onto the given point
+    /// on the image, in pixels, using the given lens model.
+    pub fn unproject(&self, lens: &impl LensModel, pixel: Vector2) -> Vector3 {
+        let norm = vec2(
+            (pixel.x - self.c_x) / self.f_x,
+            (pixel.y - self.c_y) / self.f_y,
+        );
+        let dir = lens.unproject(norm);
+        vec3(dir.x, -dir.y, -dir.z)
+    }
+}
+
+#[test]
+fn test_to_projective() {
+    use super::lens::Rectilinear;
+    let camera = PinholeCamera::new(500.0, 520.0, 310.0, 250.0);
+    let size = vec2(640.0, 480.0);
+    let proj = camera.to_projective(size, 0.1, 100.0);
+    let point = vec3(0.7, -0.4, -3.0);
+    let pixel = camera.project(&Rectilinear, point);
+    let ndc = proj * point;
+    approx::assert_relative_eq!(
+        vec2(ndc.x, ndc.y),
+        vec2(
+            2.0 * (pixel.x + 0.5) / size.x - 1.0,
+            1.0 - 2.0 * (pixel.y + 0.5) / size.y
+        ),
+        epsilon = 1e-5
+    );
+    let actual = PinholeCamera::from_projective(&proj, size);
+    approx::assert_relative_eq!(actual.f_x, camera.f_x, epsilon = 1e-3);
+    approx::assert_relative_eq!(actual.f_y, camera.f_y, epsilon = 1e-3);
+    approx::assert_relative_eq!(actual.c_x, camera.c_x, epsilon = 1e-3);
+    approx::assert_relative_eq!(actual.c_y, camera.c_y, epsilon = 1e-3);
+}
+
+#[test]
+fn test_from_projective() {
+    use super::Perspective;
+    let proj = Projective3::perspective(4.0 / 3.0, crate::PI / 2.0, 0.1, Scalar::INFINITY);
+    let camera = PinholeCamera::from_projective(&proj, vec2(640.0, 480.0));
+    approx::assert_relative_eq!(camera.f_x, 240.0, epsilon = 1e-3);
+    approx::assert_relative_eq!(camera.f_y, 240.0, epsilon = 1e-3);
+    approx::assert_relative_eq!(camera.c_x, 319.5, epsilon = 1e-3);
+    approx::assert_relative_eq!(camera.c_y, 239.5, epsilon = 1e-3);
+}
+
+#[test]
+fn test_unproject() {
+    use super::lens::BrownConrady;
+    let camera = PinholeCamera::new(500.0, 520.0, 310.0, 250.0);
+    let lens = BrownConrady {
+        k_1: -0.1,
+        k_2: 0.01,
+        ..Default::default()
+    };
+    let point = vec3(0.7, -0.4, -3.0);
+    let pixel = camera.project(&lens, point);
+    approx::assert_relative_eq!(
+        camera.unproject(&lens, pixel),
+        point.normalize(),
+        epsilon = 1e-5
+    );
+}
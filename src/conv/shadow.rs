@@ -0,0 +1,245 @@
+use super::{ndc_to_ray, LookTowards, Orthographic};
+use crate::{math, vec2, vec3, Motion3, Projective3, Rotation3, Scalar, Vector3};
+
+/// Gets the world-space corners of the slice of a perspective frustum between the given view
+/// depths (i.e. distances from the camera along the view axis).
+///
+/// `proj` is the view-to-NDC projection and `camera_to_world` positions the camera in the world.
+/// The corner at index `i` is at the positive X, Y and far extremes if bits 0, 1 and 2 of `i` are
+/// set, respectively.
+pub fn frustum_slice_corners(
+    proj: &Projective3,
+    camera_to_world: Motion3,
+    near_depth: Scalar,
+    far_depth: Scalar,
+) -> [Vector3; 8] {
+    core::array::from_fn(|i| {
+        let x = if i & 0b001 != 0 { 1.0 } else { -1.0 };
+        let y = if i & 0b010 != 0 { 1.0 } else { -1.0 };
+        let depth = if i & 0b100 != 0 { far_depth } else { near_depth };
+        let ray = ndc_to_ray(proj, vec2(x, y));
+        let t = (depth + ray.origin.z) / -ray.dir.z;
+        camera_to_world * ray.at(t)
+    })
+}
+
+/// Gets the view depths which split the range between `near_depth` and `far_depth` into `count`
+/// cascades, using the "practical split scheme", which blends logarithmic and uniform splits.
+///
+/// `lambda` controls the blend: `1` gives purely logarithmic splits and `0` gives purely uniform
+/// splits. Values around `0.5` to `0.9` are common. This returns `count + 1` depths, starting at
+/// `near_depth` and ending at `far_depth`.
+pub fn practical_split_depths(
+    near_depth: Scalar,
+    far_depth: Scalar,
+    count: usize,
+    lambda: Scalar,
+) -> impl Iterator<Item = Scalar> {
+    (0..=count).map(move |i| {
+        if i == 0 {
+            near_depth
+        } else if i == count {
+            far_depth
+        } else {
+            let f = i as Scalar / count as Scalar;
+            let log = near_depth * math::powf(far_depth / near_depth, f);
+            let uniform = near_depth + (far_depth - near_depth) * f;
+            lambda * log + (1.0 - lambda) * uniform
+        }
+    })
+}
+
+/// The transforms for rendering a shadow map from a directional light.
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShadowProjection {
+    /// The world-to-view transform for the light.
+    pub view: Motion3,
+
+    /// The orthographic view-to-NDC projection for the light.
+    pub proj: Projective3,
+}
+
+impl ShadowProjection {
+    /// Gets the combined world-to-NDC transform for the light.
+    #[inline]
+    pub fn view_proj(&self) -> Projective3 {
+        self.proj * self.view
+    }
+}
+
+/// Fits an orthographic projection for a directional light, shining in the direction `light_dir`,
+/// around the given points (typically from [`frustum_slice_corners`]).
+///
+/// If `resolution` is [`None`], the projection tightly bounds the points. Otherwise, it gives the
+/// size of the shadow map in texels, and the projection is made
+/// stable to prevent shimmering as the camera moves: its extent is based on the bounding sphere of
+/// the points, which does not change as the camera rotates, and it is positioned so that texels
+/// always fall on the same world-space grid.
+///
+/// `caster_margin` extends the near plane towards the light, so that objects outside the fitted
+/// volume can still cast shadows into it.
+///
+/// # Panics
+/// Panics if `resolution` is given and is not greater than 2.
+pub fn fit_shadow_projection(
+    light_dir: Vector3,
+    points: &[Vector3; 8],
+    resolution: Option<u32>,
+    caster_margin: Scalar,
+) -> ShadowProjection {
+    let view = Motion3::from(Rotation3::look_towards(light_dir)).inverse();
+    let light_points = points.map(|p| view * p);
+    let mut min = vec3(Scalar::INFINITY, Scalar::INFINITY, Scalar::INFINITY);
+    let mut max = vec3(Scalar::NEG_INFINITY, Scalar::NEG_INFINITY, Scalar::NEG_INFINITY);
+    for p in light_points.iter() {
+        min = vec3(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z));
+        max = vec3(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z));
+    }
+    let (left, right, bottom, top) = if let Some(resolution) = resolution {
+        assert!(resolution > 2, "shadow map resolution must be greater than 2");
+        let (center, radius) = stable_bounding_sphere(&light_points);
+
+        // Leave room for one texel of movement on each side due to snapping
+        let texel_size = 2.0 * radius / (resolution - 2) as Scalar;
+        let half_size = radius + texel_size;
+        let x = math::floor(center.x / texel_size) * texel_size;
+        let y = math::floor(center.y / texel_size) * texel_size;
+        (x - half_size, x + half_size, y - half_size, y + half_size)
+    } else {
+        (min.x, max.x, min.y, max.y)
+    };
+    let proj = Projective3::orthographic(left, right, bottom, top, -max.z - caster_margin, -min.z);
+    ShadowProjection { view, proj }
+}
+
+/// The number of fractional significand bits kept in the radius of a [`stable_bounding_sphere`],
+/// so that the radius is rounded up by a relative amount of at most `2^-8`.
+const STABLE_RADIUS_BITS: u32 = 8;
+
+/// Gets a bounding sphere for the given points whose radius depends only on the shape formed by
+/// the points, and not on their orientation.
+///
+/// The radius is rounded up to [`STABLE_RADIUS_BITS`] significand bits, since otherwise
+/// floating-point noise as the camera rotates would change the texel size of the shadow map from
+/// frame to frame. Rounding to a relative precision, rather than a fixed multiple, works the same
+/// way regardless of the scale of the scene.
+fn stable_bounding_sphere(points: &[Vector3; 8]) -> (Vector3, Scalar) {
+    let center = points.iter().fold(vec3(0.0, 0.0, 0.0), |a, p| a + *p) / 8.0;
+    let radius = points
+        .iter()
+        .map(|p| (*p - center).norm())
+        .fold(0.0, Scalar::max);
+    (center, round_up_significand(radius, STABLE_RADIUS_BITS))
+}
+
+/// Rounds a non-negative, finite value up to the nearest value whose significand has at most
+/// `bits` fractional bits, i.e. up to a multiple of a power of two that is at most `2^-bits`
+/// times the value.
+fn round_up_significand(value: Scalar, bits: u32) -> Scalar {
+    let mask = (1 << (Scalar::MANTISSA_DIGITS - 1 - bits)) - 1;
+    Scalar::from_bits((value.to_bits() + mask) & !mask)
+}
+
+#[test]
+fn test_frustum_slice_corners() {
+    use super::Perspective;
+    let proj = Projective3::perspective(1.0, crate::PI / 2.0, 1.0, Scalar::INFINITY);
+    let camera_to_world = Motion3::translate(vec3(1.0, 0.0, 0.0));
+    let corners = frustum_slice_corners(&proj, camera_to_world, 2.0, 4.0);
+    approx::assert_relative_eq!(corners[0], vec3(-1.0, -2.0, -2.0), epsilon = 1e-5);
+    approx::assert_relative_eq!(corners[3], vec3(3.0, 2.0, -2.0), epsilon = 1e-5);
+    approx::assert_relative_eq!(corners[7], vec3(5.0, 4.0, -4.0), epsilon = 1e-5);
+}
+
+#[test]
+fn test_practical_split_depths() {
+    let log = practical_split_depths(1.0, 100.0, 2, 1.0);
+    for (actual, expected) in log.zip([1.0, 10.0, 100.0]) {
+        approx::assert_relative_eq!(actual, expected, max_relative = 1e-5);
+    }
+    let uniform = practical_split_depths(1.0, 100.0, 2, 0.0);
+    for (actual, expected) in uniform.zip([1.0, 50.5, 100.0]) {
+        approx::assert_relative_eq!(actual, expected, max_relative = 1e-5);
+    }
+}
+
+#[test]
+fn test_fit_shadow_projection() {
+    use super::Perspective;
+    let proj = Projective3::perspective(1.5, 1.0, 0.5, 50.0);
+    let camera_to_world = Motion3 {
+        rotation: Rotation3::from_euler(vec3(0.2, 0.7, 0.0)),
+        offset: vec3(3.3, 1.0, -2.1),
+    };
+    let corners = frustum_slice_corners(&proj, camera_to_world, 2.0, 10.0);
+    let light_dir = vec3(0.3, -1.0, 0.2);
+    for resolution in [None, Some(1024)] {
+        let shadow = fit_shadow_projection(light_dir, &corners, resolution, 0.0);
+        let view_proj = shadow.view_proj();
+        for corner in corners.iter() {
+            let ndc = view_proj * *corner;
+            assert!(ndc.x.abs() <= 1.0 + 1e-4 && ndc.y.abs() <= 1.0 + 1e-4);
+            assert!(ndc.z >= -1e-4 && ndc.z <= 1.0 + 1e-4);
+        }
+        if let Some(resolution) = resolution {
+            // The world origin should always fall on a texel corner
+            let ndc = view_proj * vec3(0.0, 0.0, 0.0);
+            let texel_x = (ndc.x + 1.0) * 0.5 * resolution as Scalar;
+            approx::assert_relative_eq!(texel_x, texel_x.round(), epsilon = 1e-2);
+        }
+    }
+}
+
+#[test]
+fn test_fit_shadow_projection_rotation() {
+    use super::Perspective;
+    let proj = Projective3::perspective(1.5, 1.0, 0.5, 50.0);
+    let radius = |euler: Vector3| {
+        let camera_to_world = Motion3 {
+            rotation: Rotation3::from_euler(euler),
+            offset: vec3(3.3, 1.0, -2.1),
+        };
+        let corners = frustum_slice_corners(&proj, camera_to_world, 2.0, 10.0);
+        stable_bounding_sphere(&corners).1
+    };
+
+    // The texel size depends only on the radius, which should not change as the camera rotates,
+    // except when noise happens to straddle a rounding boundary, where it moves by one step
+    let expected = radius(vec3(0.0, 0.0, 0.0));
+    let step = 1.0 / (1 << STABLE_RADIUS_BITS) as Scalar;
+    for euler in [vec3(0.2, 0.7, 0.0), vec3(-1.3, 0.1, 0.4), vec3(0.0, 2.9, -0.8)] {
+        approx::assert_relative_eq!(radius(euler), expected, max_relative = step);
+    }
+}
+
+#[test]
+fn test_round_up_significand() {
+    assert_eq!(round_up_significand(0.0, 4), 0.0);
+    assert_eq!(round_up_significand(1.0, 4), 1.0);
+    assert_eq!(round_up_significand(1.0 + 1e-6, 4), 1.0625);
+    assert_eq!(round_up_significand(1.99, 4), 2.0);
+    assert_eq!(round_up_significand(3.1, 2), 3.5);
+
+    // The result scales exactly with the input, so it doesn't depend on the scale of the scene
+    for scale in [1.0 / 1024.0, 1.0 / 16.0, 16.0, 1024.0] {
+        for value in [0.3, 1.0 + 1e-6, 7.77, 123.4] {
+            let scaled = round_up_significand(value * scale, 4);
+            assert_eq!(scaled, round_up_significand(value, 4) * scale);
+        }
+    }
+
+    // Rounding never increases the value by more than the relative step
+    for value in [0.001, 0.3, 7.77, 123.4, 1e6] {
+        let rounded = round_up_significand(value, STABLE_RADIUS_BITS);
+        assert!(rounded >= value);
+        assert!(rounded <= value * (1.0 + 1.0 / (1 << STABLE_RADIUS_BITS) as Scalar));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_fit_shadow_projection_low_resolution() {
+    let corners = [vec3(0.0, 0.0, 0.0); 8];
+    fit_shadow_projection(vec3(0.0, -1.0, 0.0), &corners, Some(2), 0.0);
+}
@@ -0,0 +1,304 @@
+use super::ndc_to_ray;
+use crate::shape::{Box2, Box2i, Ray3, Size2i};
+use crate::{math, vec2, vec2i, Projective3, Scalar, Vector2, Vector2i};
+
+/// Describes a rectangular region of pixels on a render target, and the mapping between
+/// normalized device coordinates and pixels in that region.
+///
+/// Pixel coordinates follow the usual convention for images: positive X points right, positive Y
+/// points *down*, and the pixel with index `(i, j)` covers the square from `(i, j)` to
+/// `(i + 1, j + 1)`, with its center at `(i + 0.5, j + 0.5)`. Normalized device coordinates
+/// follow the conventions of this module, with positive Y pointing up.
+///
+/// Pixel coordinates are always given in physical pixels. Logical coordinates, such as those
+/// reported for the cursor on high-DPI displays, can be converted using the scale factor.
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Viewport {
+    /// The pixels covered by this viewport.
+    bounds: Box2i,
+
+    /// The number of physical pixels per logical pixel.
+    scale_factor: Scalar,
+}
+
+impl Viewport {
+    /// Constructs a [`Viewport`] which covers the given pixels, with a scale factor of 1.
+    #[inline]
+    pub const fn new(bounds: Box2i) -> Self {
+        Self {
+            bounds,
+            scale_factor: 1.0,
+        }
+    }
+
+    /// Constructs a [`Viewport`] which covers an entire render target of the given size, with a
+    /// scale factor of 1.
+    #[inline]
+    pub fn from_size(size: Size2i) -> Self {
+        Self::new(Box2i::from_min_size(vec2i(0, 0), size))
+    }
+
+    /// Sets the number of physical pixels per logical pixel for this viewport.
+    ///
+    /// # Panics
+    /// Panics if `scale_factor` is not positive and finite.
+    #[inline]
+    pub const fn with_scale_factor(mut self, scale_factor: Scalar) -> Self {
+        assert!(is_valid_scale_factor(scale_factor), "{}", SCALE_FACTOR_ERROR);
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// The pixels covered by this viewport.
+    #[inline]
+    pub const fn bounds(&self) -> Box2i {
+        self.bounds
+    }
+
+    /// The number of physical pixels per logical pixel for this viewport.
+    #[inline]
+    pub const fn scale_factor(&self) -> Scalar {
+        self.scale_factor
+    }
+
+    /// The size of this viewport, in pixels.
+    #[inline]
+    pub fn size(&self) -> Vector2 {
+        let size = self.bounds.size();
+        vec2(size.x() as Scalar, size.y() as Scalar)
+    }
+
+    /// The aspect ratio (width over height) of this viewport.
+    #[inline]
+    pub fn aspect_ratio(&self) -> Scalar {
+        let size = self.size();
+        size.x / size.y
+    }
+
+    /// Converts a point in normalized device coordinates to pixel coordinates.
+    #[inline]
+    pub fn ndc_to_pixel(&self, ndc: Vector2) -> Vector2 {
+        let min = self.bounds.min().to_float();
+        let size = self.size();
+        vec2(
+            min.x + (ndc.x + 1.0) * 0.5 * size.x,
+            min.y + (1.0 - ndc.y) * 0.5 * size.y,
+        )
+    }
+
+    /// Converts a point in pixel coordinates to normalized device coordinates.
+    #[inline]
+    pub fn pixel_to_ndc(&self, pixel: Vector2) -> Vector2 {
+        let min = self.bounds.min().to_float();
+        let size = self.size();
+        vec2(
+            (pixel.x - min.x) / size.x * 2.0 - 1.0,
+            1.0 - (pixel.y - min.y) / size.y * 2.0,
+        )
+    }
+
+    /// Gets the index of the pixel containing the given point in pixel coordinates. This may be
+    /// outside the viewport.
+    #[inline]
+    pub fn pixel_index(pixel: Vector2) -> Vector2i {
+        vec2i(math::floor(pixel.x) as i32, math::floor(pixel.y) as i32)
+    }
+
+    /// Gets the center of the pixel with the given index, in pixel coordinates.
+    #[inline]
+    pub fn pixel_center(index: Vector2i) -> Vector2 {
+        index.to_float() + vec2(0.5, 0.5)
+    }
+
+    /// Gets the index of the pixel containing the given point in normalized device coordinates,
+    /// or returns [`None`] if the point is outside the viewport.
+    #[inline]
+    pub fn ndc_to_pixel_index(&self, ndc: Vector2) -> Option<Vector2i> {
+        let index = Self::pixel_index(self.ndc_to_pixel(ndc));
+        self.bounds.contains(index).then_some(index)
+    }
+
+    /// Gets the center of the pixel with the given index, in normalized device coordinates.
+    #[inline]
+    pub fn pixel_index_to_ndc(&self, index: Vector2i) -> Vector2 {
+        self.pixel_to_ndc(Self::pixel_center(index))
+    }
+
+    /// Gets the pixels in this viewport which overlap the given rectangle in normalized device
+    /// coordinates (e.g. from [`super::project_box_bounds`]), or returns [`None`] if there are
+    /// none.
+    pub fn ndc_bounds_to_pixels(&self, bounds: Box2) -> Option<Box2i> {
+        let a = self.ndc_to_pixel(bounds.min());
+        let b = self.ndc_to_pixel(bounds.max());
+        let (min, max) = (self.bounds.min(), self.bounds.max());
+        let min_x = (math::floor(a.x.min(b.x)) as i32).max(min.x);
+        let min_y = (math::floor(a.y.min(b.y)) as i32).max(min.y);
+        let max_x = ((math::ceil(a.x.max(b.x)) as i32) - 1).min(max.x);
+        let max_y = ((math::ceil(a.y.max(b.y)) as i32) - 1).min(max.y);
+        if min_x <= max_x && min_y <= max_y {
+            Some(Box2i::from_min_max(vec2i(min_x, min_y), vec2i(max_x, max_y)))
+        } else {
+            None
+        }
+    }
+
+    /// Converts a point in logical coordinates to (physical) pixel coordinates.
+    #[inline]
+    pub fn logical_to_pixel(&self, logical: Vector2) -> Vector2 {
+        logical * self.scale_factor
+    }
+
+    /// Converts a point in (physical) pixel coordinates to logical coordinates.
+    #[inline]
+    pub fn pixel_to_logical(&self, pixel: Vector2) -> Vector2 {
+        pixel / self.scale_factor
+    }
+
+    /// Gets the ray consisting of all points which are projected onto the given point in pixel
+    /// coordinates by the given projection. See [`ndc_to_ray`].
+    #[inline]
+    pub fn pixel_to_ray(&self, proj: &Projective3, pixel: Vector2) -> Ray3 {
+        ndc_to_ray(proj, self.pixel_to_ndc(pixel))
+    }
+
+    /// Gets the ray consisting of all points which are projected under the cursor, given in
+    /// logical coordinates, by the given projection. See [`ndc_to_ray`].
+    #[inline]
+    pub fn cursor_to_ray(&self, proj: &Projective3, cursor: Vector2) -> Ray3 {
+        self.pixel_to_ray(proj, self.logical_to_pixel(cursor))
+    }
+}
+
+/// Determines whether the given scale factor is positive and finite, which also excludes NaN.
+const fn is_valid_scale_factor(scale_factor: Scalar) -> bool {
+    scale_factor > 0.0 && scale_factor < Scalar::INFINITY
+}
+
+/// The error message for an invalid scale factor.
+const SCALE_FACTOR_ERROR: &str = "viewport scale factor must be positive and finite";
+
+/// Deserialization checks that the scale factor is positive and finite.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Viewport {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Viewport")]
+        struct Raw {
+            bounds: Box2i,
+            scale_factor: Scalar,
+        }
+        let Raw { bounds, scale_factor } = Raw::deserialize(deserializer)?;
+        if is_valid_scale_factor(scale_factor) {
+            Ok(Self { bounds, scale_factor })
+        } else {
+            Err(serde::de::Error::custom(SCALE_FACTOR_ERROR))
+        }
+    }
+}
+
+impl From<Box2i> for Viewport {
+    #[inline]
+    fn from(bounds: Box2i) -> Self {
+        Self::new(bounds)
+    }
+}
+
+impl From<Size2i> for Viewport {
+    #[inline]
+    fn from(size: Size2i) -> Self {
+        Self::from_size(size)
+    }
+}
+
+#[test]
+fn test_ndc_pixel() {
+    let bounds = Box2i::from_min_size(vec2i(100, 50), crate::shape::size2i(200, 100));
+    let viewport = Viewport::new(bounds);
+    approx::assert_relative_eq!(viewport.ndc_to_pixel(vec2(-1.0, 1.0)), vec2(100.0, 50.0));
+    approx::assert_relative_eq!(viewport.ndc_to_pixel(vec2(1.0, -1.0)), vec2(300.0, 150.0));
+    approx::assert_relative_eq!(viewport.pixel_to_ndc(vec2(200.0, 100.0)), vec2(0.0, 0.0));
+    let ndc = vec2(0.3, -0.7);
+    approx::assert_relative_eq!(viewport.pixel_to_ndc(viewport.ndc_to_pixel(ndc)), ndc);
+}
+
+#[test]
+fn test_pixel_index() {
+    let viewport = Viewport::from_size(crate::shape::size2i(4, 2));
+    assert_eq!(viewport.ndc_to_pixel_index(vec2(-0.99, 0.99)), Some(vec2i(0, 0)));
+    assert_eq!(viewport.ndc_to_pixel_index(vec2(0.01, -0.01)), Some(vec2i(2, 1)));
+    assert_eq!(viewport.ndc_to_pixel_index(vec2(1.5, 0.0)), None);
+    approx::assert_relative_eq!(viewport.pixel_index_to_ndc(vec2i(0, 0)), vec2(-0.75, 0.5));
+    assert_eq!(Viewport::pixel_index(vec2(-0.5, 1.5)), vec2i(-1, 1));
+}
+
+#[test]
+fn test_ndc_bounds_to_pixels() {
+    let viewport = Viewport::from_size(crate::shape::size2i(4, 2));
+    let bounds = Box2::from_min_max(vec2(-0.6, -0.1), vec2(0.1, 2.0));
+    let pixels = Box2i::from_min_max(vec2i(0, 0), vec2i(2, 1));
+    assert_eq!(viewport.ndc_bounds_to_pixels(bounds), Some(pixels));
+    let bounds = Box2::from_min_max(vec2(1.5, -0.1), vec2(2.0, 2.0));
+    assert_eq!(viewport.ndc_bounds_to_pixels(bounds), None);
+}
+
+#[test]
+fn test_cursor_to_ray() {
+    use super::Perspective;
+    let viewport = Viewport::from_size(crate::shape::size2i(400, 200)).with_scale_factor(2.0);
+    let proj = Projective3::perspective(viewport.aspect_ratio(), crate::PI / 2.0, 1.0, 10.0);
+    let ray = viewport.cursor_to_ray(&proj, vec2(100.0, 50.0));
+    approx::assert_relative_eq!(ray.origin, crate::vec3(0.0, 0.0, -1.0), epsilon = 1e-5);
+    approx::assert_relative_eq!(ray.dir, crate::vec3(0.0, 0.0, -1.0), epsilon = 1e-5);
+}
+
+#[test]
+#[should_panic]
+fn test_zero_scale_factor() {
+    let _ = Viewport::from_size(crate::shape::size2i(4, 2)).with_scale_factor(0.0);
+}
+
+#[test]
+#[should_panic]
+fn test_nan_scale_factor() {
+    let _ = Viewport::from_size(crate::shape::size2i(4, 2)).with_scale_factor(Scalar::NAN);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+    let tokens = |scale_factor: Scalar| {
+        [
+            Token::Struct { name: "Viewport", len: 2 },
+            Token::Str("bounds"),
+            Token::Struct { name: "Box2i", len: 2 },
+            Token::Str("min"),
+            Token::Struct { name: "Vector2i", len: 2 },
+            Token::Str("x"),
+            Token::I32(0),
+            Token::Str("y"),
+            Token::I32(0),
+            Token::StructEnd,
+            Token::Str("max"),
+            Token::Struct { name: "Vector2i", len: 2 },
+            Token::Str("x"),
+            Token::I32(3),
+            Token::Str("y"),
+            Token::I32(1),
+            Token::StructEnd,
+            Token::StructEnd,
+            Token::Str("scale_factor"),
+            Token::F32(scale_factor),
+            Token::StructEnd,
+        ]
+    };
+    let viewport = Viewport::from_size(crate::shape::size2i(4, 2)).with_scale_factor(2.0);
+    assert_tokens(&viewport, &tokens(2.0));
+
+    // The scale factor must be positive and finite
+    assert_de_tokens_error::<Viewport>(&tokens(0.0), SCALE_FACTOR_ERROR);
+    assert_de_tokens_error::<Viewport>(&tokens(-1.0), SCALE_FACTOR_ERROR);
+    assert_de_tokens_error::<Viewport>(&tokens(Scalar::NAN), SCALE_FACTOR_ERROR);
+    assert_de_tokens_error::<Viewport>(&tokens(Scalar::INFINITY), SCALE_FACTOR_ERROR);
+}
@@ -0,0 +1,178 @@
+//! Contains implementations for applying transforms to [`Diff`] values, where the value transforms
+//! as a point and its derivatives transform as vectors (i.e. ignoring translation).
+use crate::{Affine2, Affine3, Matrix2, Matrix3, Motion2, Motion3, Rotation2, Rotation3};
+use crate::{Similarity2, Similarity3, Vector2, Vector3};
+use diffvec::{Diff, PolyMappable};
+
+/// A transform which can be applied to the derivatives of a [`Diff`] of the vector type `V`.
+trait DiffLinear<V> {
+    /// The type of the linear component of the transform.
+    type Linear: core::ops::Mul<V, Output = V>;
+
+    /// Gets the linear component of this transform, which is applied to the derivatives.
+    fn diff_linear(&self) -> Self::Linear;
+}
+
+/// Implements multiplication of a transform with a [`Diff`] of the given vector type. `$linear`
+/// maps the transform to its linear component, which is applied to the derivatives.
+macro_rules! impl_diff_mul {
+    ($trans:ty, $vec:ty, $lin:ty, |$t:ident| $linear:expr) => {
+        impl DiffLinear<$vec> for $trans {
+            type Linear = $lin;
+            #[inline]
+            fn diff_linear(&self) -> $lin {
+                let $t = self;
+                $linear
+            }
+        }
+
+        impl<Poly: PolyMappable> core::ops::Mul<Diff<Poly, $vec>> for $trans {
+            type Output = Diff<Poly, $vec>;
+            fn mul(self, rhs: Diff<Poly, $vec>) -> Diff<Poly, $vec> {
+                let linear = self.diff_linear();
+                Diff {
+                    value: self * rhs.value,
+                    poly: Poly::map(rhs.poly, |d| linear * d),
+                }
+            }
+        }
+    };
+}
+
+impl_diff_mul!(Rotation2, Vector2, Rotation2, |t| *t);
+impl_diff_mul!(Motion2, Vector2, Rotation2, |t| t.rotation);
+impl_diff_mul!(Similarity2, Vector2, Matrix2, |t| t.linear());
+impl_diff_mul!(Affine2, Vector2, Matrix2, |t| t.linear);
+impl_diff_mul!(Rotation3, Vector3, Rotation3, |t| *t);
+impl_diff_mul!(Motion3, Vector3, Rotation3, |t| t.rotation);
+impl_diff_mul!(Similarity3, Vector3, Matrix3, |t| t.linear());
+impl_diff_mul!(Affine3, Vector3, Matrix3, |t| t.linear);
+
+/// Checks that the map applied to the derivatives by `trans` matches a central finite-difference
+/// derivative of `trans` applied to points.
+#[cfg(test)]
+fn check_diff_linear<T>(trans: T)
+where
+    T: DiffLinear<Vector3> + core::ops::Mul<Vector3, Output = Vector3> + Copy,
+{
+    use crate::vec3;
+    let point = vec3(0.3, -1.2, 2.0);
+    let h = 1e-2;
+    for dir in [
+        vec3(1.0, 0.0, 0.0),
+        vec3(0.5, 0.1, -0.7),
+        vec3(-0.2, 0.9, 0.4),
+    ] {
+        let expected = (trans * (point + dir * h) - trans * (point - dir * h)) / (2.0 * h);
+        approx::assert_relative_eq!(trans.diff_linear() * dir, expected, epsilon = 1e-3);
+    }
+}
+
+#[test]
+fn test_diff_linear() {
+    use crate::vec3;
+    let rotation = Rotation3::from_euler(vec3(0.4, -0.9, 1.3));
+    check_diff_linear(rotation);
+
+    // Use a large offset, so that it would be obvious if it leaked into the derivatives
+    let motion = Motion3 {
+        rotation,
+        offset: vec3(10.0, -20.0, 5.0),
+    };
+    check_diff_linear(motion);
+    check_diff_linear(Affine3 {
+        linear: Matrix3 {
+            x: vec3(2.0, 0.5, 0.0),
+            y: vec3(-0.3, 1.0, 0.8),
+            z: vec3(0.1, 0.0, -1.5),
+        },
+        offset: vec3(-7.0, 3.0, 12.0),
+    });
+}
+
+/// Constructs a [`Diff`] with the given value and first and second derivatives.
+#[cfg(test)]
+fn new_diff<V>(value: V, first: V, second: V) -> Diff<diffvec::Poly2, V> {
+    Diff {
+        value,
+        poly: diffvec::Poly2::new(first, second),
+    }
+}
+
+/// Gets the derivative terms of a [`Diff`], in order.
+#[cfg(test)]
+fn diff_terms<Poly: PolyMappable, V>(diff: Diff<Poly, V>) -> Vec<V> {
+    let terms = core::cell::RefCell::new(Vec::new());
+    let _ = Poly::map(diff.poly, |d| terms.borrow_mut().push(d));
+    terms.into_inner()
+}
+
+/// Multiplies a [`Diff`] by the given transform and checks that the value transforms as a point
+/// and each derivative transforms as a vector, i.e. as the difference of two transformed points.
+#[cfg(test)]
+macro_rules! check_diff_mul {
+    ($trans:expr, $zero:expr, $value:expr, $first:expr, $second:expr) => {{
+        let trans = $trans;
+        let res = trans * new_diff($value, $first, $second);
+        approx::assert_relative_eq!(res.value, trans * $value, epsilon = 1e-4);
+        let terms = diff_terms(res);
+        assert_eq!(terms.len(), 2);
+        for (actual, d) in terms.into_iter().zip([$first, $second]) {
+            let expected = trans * d - trans * $zero;
+            approx::assert_relative_eq!(actual, expected, epsilon = 1e-4);
+        }
+    }};
+}
+
+#[test]
+fn test_diff_mul_2() {
+    use crate::vec2;
+    let zero = vec2(0.0, 0.0);
+    let (value, first, second) = (vec2(0.3, -1.2), vec2(0.5, 0.1), vec2(-0.2, 0.9));
+    let rotation = Rotation2::from_angle(0.7);
+
+    // Use a large offset, so that it would be obvious if it leaked into the derivatives
+    let offset = vec2(10.0, -20.0);
+    check_diff_mul!(rotation, zero, value, first, second);
+    check_diff_mul!(Motion2 { rotation, offset }, zero, value, first, second);
+    let similarity = Similarity2 {
+        rotation,
+        scaling: 2.5,
+        offset,
+    };
+    check_diff_mul!(similarity, zero, value, first, second);
+    let affine = Affine2 {
+        linear: Matrix2 {
+            x: vec2(2.0, 0.5),
+            y: vec2(-0.3, 1.0),
+        },
+        offset,
+    };
+    check_diff_mul!(affine, zero, value, first, second);
+}
+
+#[test]
+fn test_diff_mul_3() {
+    use crate::vec3;
+    let zero = vec3(0.0, 0.0, 0.0);
+    let (value, first, second) = (vec3(0.3, -1.2, 2.0), vec3(0.5, 0.1, -0.7), vec3(-0.2, 0.9, 0.4));
+    let rotation = Rotation3::from_euler(vec3(0.4, -0.9, 1.3));
+    let offset = vec3(10.0, -20.0, 5.0);
+    check_diff_mul!(rotation, zero, value, first, second);
+    check_diff_mul!(Motion3 { rotation, offset }, zero, value, first, second);
+    let similarity = Similarity3 {
+        rotation,
+        scaling: 2.5,
+        offset,
+    };
+    check_diff_mul!(similarity, zero, value, first, second);
+    let affine = Affine3 {
+        linear: Matrix3 {
+            x: vec3(2.0, 0.5, 0.0),
+            y: vec3(-0.3, 1.0, 0.8),
+            z: vec3(0.1, 0.0, -1.5),
+        },
+        offset,
+    };
+    check_diff_mul!(affine, zero, value, first, second);
+}
@@ -0,0 +1,124 @@
+use super::{vec2d, Vector2d};
+use crate::shape::Box2;
+use crate::vec2;
+
+/// A double-precision axis-aligned rectangle in two-dimensional space. See [`Box2`].
+#[repr(C)]
+#[derive(Default, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Box2d {
+    min: Vector2d,
+    max: Vector2d,
+}
+
+impl Box2d {
+    /// A [`Box2d`] that contains all points.
+    pub const ALL: Box2d = Self {
+        min: vec2d(f64::NEG_INFINITY, f64::NEG_INFINITY),
+        max: vec2d(f64::INFINITY, f64::INFINITY),
+    };
+
+    /// Constructs a [`Box2d`] which contains only the given point.
+    #[inline]
+    pub const fn only(point: Vector2d) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// Constructs a [`Box2d`] from its minimum and maximum coordinates.
+    #[inline]
+    pub const fn from_min_max(min: Vector2d, max: Vector2d) -> Self {
+        Self { min, max }
+    }
+
+    /// The minimum coordinates of the box.
+    #[inline]
+    pub const fn min(&self) -> Vector2d {
+        self.min
+    }
+
+    /// The maximum coordinates of the box.
+    #[inline]
+    pub const fn max(&self) -> Vector2d {
+        self.max
+    }
+
+    /// Determines whether this box contains the given point.
+    #[inline]
+    pub const fn contains(&self, point: Vector2d) -> bool {
+        self.min.x <= point.x
+            && self.min.y <= point.y
+            && point.x <= self.max.x
+            && point.y <= self.max.y
+    }
+
+    /// Determines whether this box has any points in common with the given box.
+    #[inline]
+    pub const fn overlaps(&self, other: Box2d) -> bool {
+        self.min.x <= other.max.x
+            && self.min.y <= other.max.y
+            && other.min.x <= self.max.x
+            && other.min.y <= self.max.y
+    }
+
+    /// Gets the smallest box that contains both this box and the given box.
+    #[inline]
+    pub fn bound(&self, other: Box2d) -> Box2d {
+        Self {
+            min: vec2d(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: vec2d(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    /// Converts this box to single precision. The bounds are rounded outwards, so that the
+    /// resulting box contains this box.
+    pub fn to_f32(&self) -> Box2 {
+        Box2::from_min_max(
+            vec2(round_down(self.min.x), round_down(self.min.y)),
+            vec2(round_up(self.max.x), round_up(self.max.y)),
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_util::impl_deserialize_box!(Box2d, "Box2d", Vector2d, [x, y]);
+
+impl From<Box2> for Box2d {
+    #[inline]
+    fn from(bx: Box2) -> Self {
+        Self::from_min_max(bx.min().into(), bx.max().into())
+    }
+}
+
+/// Gets the greatest single-precision value which is less than or equal to the given value.
+fn round_down(value: f64) -> f32 {
+    -round_up(-value)
+}
+
+/// Gets the least single-precision value which is greater than or equal to the given value.
+fn round_up(value: f64) -> f32 {
+    let res = value as f32;
+    if res.is_nan() || f64::from(res) >= value {
+        res
+    } else if res == 0.0 {
+        f32::from_bits(1)
+    } else if res > 0.0 {
+        f32::from_bits(res.to_bits() + 1)
+    } else {
+        f32::from_bits(res.to_bits() - 1)
+    }
+}
+
+#[test]
+fn test_to_f32() {
+    let bx = Box2d::from_min_max(vec2d(0.1, -1e-50), vec2d(1e8 + 1.0, 0.3));
+    let res = Box2d::from(bx.to_f32());
+    assert!(res.contains(bx.min()) && res.contains(bx.max()));
+    assert_eq!(Box2d::ALL.to_f32(), Box2::ALL);
+    let bx = Box2::from_min_max(vec2(0.1, -2.0), vec2(0.5, 3.0));
+    assert_eq!(Box2d::from(bx).to_f32(), bx);
+}
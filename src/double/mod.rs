@@ -0,0 +1,21 @@
+//! This module contains double-precision versions of the transform and shape types in this crate,
+//! for applications where single precision is insufficient, such as planet-scale worlds.
+//!
+//! Each type can be converted losslessly from its single-precision counterpart using [`From`],
+//! and back using `to_f32`, which rounds to a nearby representable value. To avoid losing
+//! precision when rendering a large world, compose transforms relative to the camera in double
+//! precision, e.g. `(camera.inverse() * object).to_f32()`, and only then convert the result to
+//! single precision.
+mod box2;
+mod rot2;
+mod rot3;
+mod trans2;
+mod trans3;
+mod vec;
+
+pub use box2::Box2d;
+pub use rot2::Rotation2d;
+pub use rot3::Rotation3d;
+pub use trans2::{Affine2d, Motion2d, Similarity2d};
+pub use trans3::{Affine3d, Motion3d, Projective3d, Similarity3d};
+pub use vec::{vec2d, vec3d, vec4d, Matrix2d, Matrix3d, Matrix4d, Vector2d, Vector3d, Vector4d};
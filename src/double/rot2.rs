@@ -0,0 +1,139 @@
+use super::{vec2d, Matrix2d, Vector2d};
+use crate::{math, Rotation2};
+
+/// A double-precision rotational transform in two-dimensional space. See [`Rotation2`].
+#[repr(transparent)]
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Rotation2d {
+    /// The tangent of half the angle of the rotation (positive values correspond to
+    /// counter-clockwise rotations).
+    tan_half_angle: f64,
+}
+
+impl Rotation2d {
+    /// The maximum absolute value for a `tan_half_angle` value that can be handled using general
+    /// logic.
+    const NORMAL_THRESHOLD: f64 = 1e150;
+
+    /// The identity rotation.
+    pub const IDENTITY: Rotation2d = Rotation2d {
+        tan_half_angle: 0.0,
+    };
+
+    /// Constructs a rotation from the tangent of half of its counter-clockwise angle. This may be
+    /// infinite, for a rotation by 180 degrees.
+    #[inline]
+    pub const fn from_tan_half_angle(tan_half_angle: f64) -> Self {
+        Self { tan_half_angle }
+    }
+
+    /// Gets the tangent of half of the counter-clockwise angle of this rotation.
+    #[inline]
+    pub const fn tan_half_angle(&self) -> f64 {
+        self.tan_half_angle
+    }
+
+    /// Constructs a rotation which rotates counter-clockwise by the given angle, in radians.
+    pub fn from_angle(angle: f64) -> Self {
+        Self {
+            tan_half_angle: math::tan(angle / 2.0),
+        }
+    }
+
+    /// Gets the "inverse" of this rotation, which rotates by the same amount in the opposite
+    /// direction.
+    pub const fn inverse(&self) -> Self {
+        Self {
+            tan_half_angle: -self.tan_half_angle,
+        }
+    }
+
+    /// Computes the `sin` and `cos` of the angle for this rotation.
+    pub fn angle_sin_cos(&self) -> (f64, f64) {
+        let x = self.tan_half_angle;
+        if x.abs() < Self::NORMAL_THRESHOLD {
+            let x_sqr = x * x;
+            let y = 1.0 / (1.0 + x_sqr);
+            (2.0 * x * y, (1.0 - x_sqr) * y)
+        } else {
+            (2.0 / x, -1.0)
+        }
+    }
+
+    /// Converts this rotation to single precision, rounding to the nearest representable
+    /// rotation.
+    #[inline]
+    pub fn to_f32(&self) -> Rotation2 {
+        Rotation2::from_tan_half_angle(self.tan_half_angle as f32)
+    }
+}
+
+impl Default for Rotation2d {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Rotation2> for Rotation2d {
+    #[inline]
+    fn from(rotation: Rotation2) -> Self {
+        Self::from_tan_half_angle(rotation.tan_half_angle().into())
+    }
+}
+
+impl core::ops::Mul<Rotation2d> for Rotation2d {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let x = self.tan_half_angle;
+        let y = rhs.tan_half_angle;
+        let tan_half_angle = if x.abs() <= Self::NORMAL_THRESHOLD {
+            if y.abs() <= Self::NORMAL_THRESHOLD {
+                (x + y) / (1.0 - x * y)
+            } else {
+                1.0 / (1.0 / y - x)
+            }
+        } else if y.abs() <= Self::NORMAL_THRESHOLD {
+            1.0 / (1.0 / x - y)
+        } else {
+            (-1.0 / x) + (-1.0 / y)
+        };
+        Self { tan_half_angle }
+    }
+}
+
+impl core::ops::Mul<Vector2d> for Rotation2d {
+    type Output = Vector2d;
+    fn mul(self, rhs: Vector2d) -> Vector2d {
+        let (sin, cos) = self.angle_sin_cos();
+        vec2d(cos * rhs.x - sin * rhs.y, sin * rhs.x + cos * rhs.y)
+    }
+}
+
+impl From<Rotation2d> for Matrix2d {
+    fn from(rotation: Rotation2d) -> Matrix2d {
+        let (sin, cos) = rotation.angle_sin_cos();
+        Matrix2d {
+            x: vec2d(cos, sin),
+            y: vec2d(-sin, cos),
+        }
+    }
+}
+
+#[test]
+fn test_compose() {
+    let mut angle = 1.0f64;
+    let mut rot = Rotation2d::from_angle(angle);
+    let delta_angle = 1.2f64;
+    let delta_rot = Rotation2d::from_angle(delta_angle);
+    for _ in 0..100 {
+        angle += delta_angle;
+        rot = rot * delta_rot;
+        let vec = vec2d(angle.cos(), angle.sin());
+        assert!((rot * vec2d(1.0, 0.0) - vec).norm() < 1e-10);
+    }
+    let f32_rot = Rotation2::from_angle(0.3);
+    assert_eq!(Rotation2d::from(f32_rot).to_f32(), f32_rot);
+}
@@ -0,0 +1,193 @@
+use super::{vec3d, Matrix3d, Vector3d};
+use crate::{math, Rotation3};
+
+/// A double-precision rotation in three-dimensional space. See [`Rotation3`].
+#[repr(C)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Rotation3d {
+    /// The vector part of the quaternion.
+    x_y_z: Vector3d,
+
+    /// The scalar part of the quaternion.
+    w: f64,
+}
+
+impl Rotation3d {
+    /// Constructs a [`Rotation3d`] from its quaternion components.
+    ///
+    /// This assumes that the input is normalized, i.e. `w² + x² + y² + z² = 1`.
+    #[inline]
+    pub const fn new_unchecked(w: f64, x: f64, y: f64, z: f64) -> Self {
+        Self {
+            x_y_z: vec3d(x, y, z),
+            w,
+        }
+    }
+
+    /// The identity rotation.
+    pub const IDENTITY: Rotation3d = Self::new_unchecked(1.0, 0.0, 0.0, 0.0);
+
+    /// Constructs a rotation about the given vector with an angle which is equal to its magnitude,
+    /// in radians, following the right-hand rule.
+    pub fn from_euler(vec: Vector3d) -> Self {
+        let angle = vec.norm();
+        let (h_sin, h_cos) = math::sin_cos(angle / 2.0);
+        let h_sinc = if angle > 1e-6 {
+            h_sin / angle
+        } else {
+            // Use a series approximation of `sin(angle / 2) / angle` for small angles
+            0.5 - angle * angle / 48.0
+        };
+        Self::new_unchecked(h_cos, vec.x * h_sinc, vec.y * h_sinc, vec.z * h_sinc)
+    }
+
+    /// Gets the scalar part of the quaternion for this rotation.
+    #[inline]
+    pub const fn w(&self) -> f64 {
+        self.w
+    }
+
+    /// Gets the vector part of the quaternion for this rotation.
+    #[inline]
+    pub const fn x_y_z(&self) -> Vector3d {
+        self.x_y_z
+    }
+
+    /// Gets the inverse of this rotation.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Self {
+            x_y_z: -self.x_y_z,
+            w: self.w,
+        }
+    }
+
+    /// Converts this rotation to single precision, rounding to a nearby representable rotation.
+    pub fn to_f32(&self) -> Rotation3 {
+        let x_y_z = self.x_y_z.to_f32();
+        Rotation3::new_unchecked(self.w as f32, x_y_z.x, x_y_z.y, x_y_z.z)
+    }
+}
+
+impl Default for Rotation3d {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Deserialization checks that the quaternion is normalized, with the same tolerance as
+/// [`Rotation3`].
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rotation3d {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Rotation3d")]
+        struct Raw {
+            x_y_z: Vector3d,
+            w: f64,
+        }
+        let Raw { x_y_z, w } = Raw::deserialize(deserializer)?;
+        crate::serde_util::check_quaternion(w * w + x_y_z.norm_squared())?;
+        Ok(Self { x_y_z, w })
+    }
+}
+
+impl From<Rotation3> for Rotation3d {
+    #[inline]
+    fn from(rotation: Rotation3) -> Self {
+        Self {
+            x_y_z: rotation.x_y_z().into(),
+            w: rotation.w().into(),
+        }
+    }
+}
+
+impl core::ops::Mul<Rotation3d> for Rotation3d {
+    type Output = Rotation3d;
+    fn mul(self, rhs: Rotation3d) -> Rotation3d {
+        let w = self.w * rhs.w - self.x_y_z.dot(&rhs.x_y_z);
+        let x_y_z = rhs.x_y_z * self.w + self.x_y_z * rhs.w + self.x_y_z.cross(&rhs.x_y_z);
+
+        // Use a polynomial approximation for square root centered around 1, since a full square
+        // root for normalization is overkill
+        let norm_sqr = w * w + x_y_z.norm_squared();
+        let i_norm = 2.0 / (1.0 + norm_sqr);
+        Self {
+            x_y_z: x_y_z * i_norm,
+            w: w * i_norm,
+        }
+    }
+}
+
+impl core::ops::Mul<Vector3d> for Rotation3d {
+    type Output = Vector3d;
+    fn mul(self, rhs: Vector3d) -> Vector3d {
+        // Apply `v + 2w(q × v) + 2q × (q × v)`, where `q` is the vector part of the quaternion
+        let t = self.x_y_z.cross(&rhs) * 2.0;
+        rhs + t * self.w + self.x_y_z.cross(&t)
+    }
+}
+
+impl From<Rotation3d> for Matrix3d {
+    fn from(rot: Rotation3d) -> Matrix3d {
+        let wx2 = 2.0 * rot.w * rot.x_y_z.x;
+        let wy2 = 2.0 * rot.w * rot.x_y_z.y;
+        let wz2 = 2.0 * rot.w * rot.x_y_z.z;
+        let xx2 = 2.0 * rot.x_y_z.x * rot.x_y_z.x;
+        let xy2 = 2.0 * rot.x_y_z.x * rot.x_y_z.y;
+        let xz2 = 2.0 * rot.x_y_z.x * rot.x_y_z.z;
+        let yy2 = 2.0 * rot.x_y_z.y * rot.x_y_z.y;
+        let yz2 = 2.0 * rot.x_y_z.y * rot.x_y_z.z;
+        let zz2 = 2.0 * rot.x_y_z.z * rot.x_y_z.z;
+        Matrix3d {
+            x: vec3d(1.0 - yy2 - zz2, xy2 + wz2, xz2 - wy2),
+            y: vec3d(xy2 - wz2, 1.0 - xx2 - zz2, yz2 + wx2),
+            z: vec3d(xz2 + wy2, yz2 - wx2, 1.0 - xx2 - yy2),
+        }
+    }
+}
+
+#[test]
+fn test_compose() {
+    let a = Rotation3d::from_euler(vec3d(1.0, 0.0, 0.0));
+    let b = Rotation3d::from_euler(vec3d(0.0, 1.5, 0.0));
+    let c = Rotation3d::from_euler(vec3d(0.0, 0.0, 0.5));
+    let x = vec3d(5.0, 7.0, 11.0);
+    assert!((a * b * c * x - a * (b * (c * x))).norm() < 1e-12);
+    assert!((Matrix3d::from(a * b) * x - a * (b * x)).norm() < 1e-12);
+    let f32_rot = Rotation3::from_euler(crate::vec3(0.3, -1.2, 0.5));
+    assert_eq!(Rotation3d::from(f32_rot).to_f32(), f32_rot);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+    let tokens = |x: f64, w: f64| {
+        [
+            Token::Struct { name: "Rotation3d", len: 2 },
+            Token::Str("x_y_z"),
+            Token::Struct { name: "Vector3d", len: 3 },
+            Token::Str("x"),
+            Token::F64(x),
+            Token::Str("y"),
+            Token::F64(0.0),
+            Token::Str("z"),
+            Token::F64(0.0),
+            Token::StructEnd,
+            Token::Str("w"),
+            Token::F64(w),
+            Token::StructEnd,
+        ]
+    };
+    assert_tokens(&Rotation3d::new_unchecked(0.8, 0.6, 0.0, 0.0), &tokens(0.6, 0.8));
+
+    // Non-normalized quaternions are rejected
+    assert_de_tokens_error::<Rotation3d>(
+        &tokens(0.1, 1.0),
+        "rotation quaternion is not normalized",
+    );
+}
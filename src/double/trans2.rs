@@ -0,0 +1,306 @@
+use super::{vec2d, Matrix2d, Rotation2d, Vector2d};
+use crate::{Affine2, Motion2, Similarity2};
+
+/// A double-precision transform in two-dimensional space consisting of rotation and translation.
+/// See [`Motion2`].
+#[repr(C)]
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Motion2d {
+    /// The rotation component of this transform, applied before translation.
+    pub rotation: Rotation2d,
+
+    /// The offset for the translation component of this transform, applied after rotation.
+    pub offset: Vector2d,
+}
+
+impl Motion2d {
+    /// The identity motion.
+    pub const fn identity() -> Self {
+        Self {
+            rotation: Rotation2d::IDENTITY,
+            offset: vec2d(0.0, 0.0),
+        }
+    }
+
+    /// Constructs a motion which translates by the given offset.
+    pub const fn translate(offset: Vector2d) -> Self {
+        Self {
+            rotation: Rotation2d::IDENTITY,
+            offset,
+        }
+    }
+
+    /// Gets the inverse of this motion.
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        Self {
+            rotation,
+            offset: rotation * -self.offset,
+        }
+    }
+
+    /// Converts this motion to single precision, rounding to the nearest representable motion.
+    pub fn to_f32(&self) -> Motion2 {
+        Motion2 {
+            rotation: self.rotation.to_f32(),
+            offset: self.offset.to_f32(),
+        }
+    }
+}
+
+impl From<Motion2> for Motion2d {
+    fn from(motion: Motion2) -> Self {
+        Self {
+            rotation: motion.rotation.into(),
+            offset: motion.offset.into(),
+        }
+    }
+}
+
+impl From<Rotation2d> for Motion2d {
+    fn from(rotation: Rotation2d) -> Motion2d {
+        Motion2d {
+            rotation,
+            offset: vec2d(0.0, 0.0),
+        }
+    }
+}
+
+impl_trans_mul!(Rotation2d, Motion2d);
+
+impl core::ops::Mul<Motion2d> for Motion2d {
+    type Output = Motion2d;
+    fn mul(self, rhs: Motion2d) -> Motion2d {
+        Motion2d {
+            rotation: self.rotation * rhs.rotation,
+            offset: self.rotation * rhs.offset + self.offset,
+        }
+    }
+}
+
+impl core::ops::Mul<Vector2d> for Motion2d {
+    type Output = Vector2d;
+    fn mul(self, rhs: Vector2d) -> Vector2d {
+        self.rotation * rhs + self.offset
+    }
+}
+
+/// A double-precision transform in two-dimensional space consisting of rotation, translation and
+/// uniform scaling. See [`Similarity2`].
+#[repr(C)]
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Similarity2d {
+    /// The rotation component of this transform, applied before translation.
+    pub rotation: Rotation2d,
+
+    /// The scaling component of this transform, applied before translation.
+    pub scaling: f64,
+
+    /// The offset for the translation component of this transform, applied after rotation and
+    /// scaling.
+    pub offset: Vector2d,
+}
+
+impl Similarity2d {
+    /// The identity similarity.
+    pub const fn identity() -> Self {
+        Self {
+            rotation: Rotation2d::IDENTITY,
+            scaling: 1.0,
+            offset: vec2d(0.0, 0.0),
+        }
+    }
+
+    /// Gets the inverse of this similarity.
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        let scaling = 1.0 / self.scaling;
+        Self {
+            rotation,
+            scaling,
+            offset: rotation * (-self.offset * scaling),
+        }
+    }
+
+    /// Gets the linear component (consisting of rotation and scaling) for this similarity.
+    pub fn linear(&self) -> Matrix2d {
+        Matrix2d::from(self.rotation) * self.scaling
+    }
+
+    /// Converts this similarity to single precision, rounding to the nearest representable
+    /// similarity.
+    pub fn to_f32(&self) -> Similarity2 {
+        Similarity2 {
+            rotation: self.rotation.to_f32(),
+            scaling: self.scaling as f32,
+            offset: self.offset.to_f32(),
+        }
+    }
+}
+
+impl From<Similarity2> for Similarity2d {
+    fn from(similarity: Similarity2) -> Self {
+        Self {
+            rotation: similarity.rotation.into(),
+            scaling: similarity.scaling.into(),
+            offset: similarity.offset.into(),
+        }
+    }
+}
+
+impl From<Rotation2d> for Similarity2d {
+    fn from(rotation: Rotation2d) -> Similarity2d {
+        Motion2d::from(rotation).into()
+    }
+}
+
+impl From<Motion2d> for Similarity2d {
+    fn from(motion: Motion2d) -> Similarity2d {
+        Similarity2d {
+            rotation: motion.rotation,
+            scaling: 1.0,
+            offset: motion.offset,
+        }
+    }
+}
+
+impl_trans_mul!(Rotation2d, Similarity2d);
+impl_trans_mul!(Motion2d, Similarity2d);
+
+impl core::ops::Mul<Similarity2d> for Similarity2d {
+    type Output = Similarity2d;
+    fn mul(self, rhs: Similarity2d) -> Similarity2d {
+        Similarity2d {
+            rotation: self.rotation * rhs.rotation,
+            scaling: self.scaling * rhs.scaling,
+            offset: self.rotation * (rhs.offset * self.scaling) + self.offset,
+        }
+    }
+}
+
+impl core::ops::Mul<Vector2d> for Similarity2d {
+    type Output = Vector2d;
+    fn mul(self, rhs: Vector2d) -> Vector2d {
+        self.rotation * (rhs * self.scaling) + self.offset
+    }
+}
+
+/// A double-precision affine transform in two-dimensional space. See [`Affine2`].
+#[repr(C)]
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Affine2d {
+    /// The linear component of this transform, applied before translation.
+    pub linear: Matrix2d,
+
+    /// The offset for the translation component of this transform, applied after the linear
+    /// component.
+    pub offset: Vector2d,
+}
+
+impl Affine2d {
+    /// The identity transform.
+    pub const fn identity() -> Self {
+        Self {
+            linear: Matrix2d::identity(),
+            offset: vec2d(0.0, 0.0),
+        }
+    }
+
+    /// Gets the inverse of this affine transform.
+    pub fn inverse(&self) -> Self {
+        let linear = self.linear.inverse();
+        Self {
+            linear,
+            offset: linear * (-self.offset),
+        }
+    }
+
+    /// Converts this transform to single precision, rounding to the nearest representable
+    /// transform.
+    pub fn to_f32(&self) -> Affine2 {
+        Affine2 {
+            linear: self.linear.to_f32(),
+            offset: self.offset.to_f32(),
+        }
+    }
+}
+
+impl From<Affine2> for Affine2d {
+    fn from(affine: Affine2) -> Self {
+        Self {
+            linear: affine.linear.into(),
+            offset: affine.offset.into(),
+        }
+    }
+}
+
+impl From<Rotation2d> for Affine2d {
+    fn from(rotation: Rotation2d) -> Affine2d {
+        Motion2d::from(rotation).into()
+    }
+}
+
+impl From<Motion2d> for Affine2d {
+    fn from(motion: Motion2d) -> Affine2d {
+        Affine2d {
+            linear: Matrix2d::from(motion.rotation),
+            offset: motion.offset,
+        }
+    }
+}
+
+impl From<Similarity2d> for Affine2d {
+    fn from(similarity: Similarity2d) -> Affine2d {
+        Affine2d {
+            linear: similarity.linear(),
+            offset: similarity.offset,
+        }
+    }
+}
+
+impl_trans_mul!(Rotation2d, Affine2d);
+impl_trans_mul!(Motion2d, Affine2d);
+impl_trans_mul!(Similarity2d, Affine2d);
+
+impl core::ops::Mul<Affine2d> for Affine2d {
+    type Output = Affine2d;
+    fn mul(self, rhs: Affine2d) -> Affine2d {
+        Affine2d {
+            linear: self.linear * rhs.linear,
+            offset: self.linear * rhs.offset + self.offset,
+        }
+    }
+}
+
+impl core::ops::Mul<Vector2d> for Affine2d {
+    type Output = Vector2d;
+    fn mul(self, rhs: Vector2d) -> Vector2d {
+        self.linear * rhs + self.offset
+    }
+}
+
+#[test]
+fn test_affine_compose() {
+    let a = Affine2d::from(Similarity2d {
+        rotation: Rotation2d::from_angle(1.0),
+        scaling: 2.0,
+        offset: vec2d(1.0, 2.0),
+    });
+    let b = Motion2d {
+        rotation: Rotation2d::from_angle(0.5),
+        offset: vec2d(2.0, 1.0),
+    };
+    let x = vec2d(5.0, 7.0);
+    assert!((a * (b * x) - (a * b) * x).norm() < 1e-12);
+    assert!((a.inverse() * (a * x) - x).norm() < 1e-12);
+    assert!((b.inverse() * (b * x) - x).norm() < 1e-12);
+}
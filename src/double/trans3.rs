@@ -0,0 +1,455 @@
+use super::{vec3d, vec4d, Matrix3d, Matrix4d, Rotation3d, Vector3d, Vector4d};
+use crate::{Affine3, Motion3, Projective3, Similarity3};
+
+/// A double-precision transform in three-dimensional space consisting of rotation and
+/// translation. See [`Motion3`].
+#[repr(C)]
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Motion3d {
+    /// The rotation component of this transform, applied before translation.
+    pub rotation: Rotation3d,
+
+    /// The offset for the translation component of this transform, applied after rotation.
+    pub offset: Vector3d,
+}
+
+impl Motion3d {
+    /// The identity motion.
+    pub const fn identity() -> Self {
+        Self {
+            rotation: Rotation3d::IDENTITY,
+            offset: vec3d(0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Constructs a motion which translates by the given offset.
+    pub const fn translate(offset: Vector3d) -> Self {
+        Self {
+            rotation: Rotation3d::IDENTITY,
+            offset,
+        }
+    }
+
+    /// Gets the inverse of this motion.
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        Self {
+            rotation,
+            offset: rotation * -self.offset,
+        }
+    }
+
+    /// Converts this motion to single precision, rounding to a nearby representable motion.
+    pub fn to_f32(&self) -> Motion3 {
+        Motion3 {
+            rotation: self.rotation.to_f32(),
+            offset: self.offset.to_f32(),
+        }
+    }
+}
+
+impl Default for Motion3d {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<Motion3> for Motion3d {
+    fn from(motion: Motion3) -> Self {
+        Self {
+            rotation: motion.rotation.into(),
+            offset: motion.offset.into(),
+        }
+    }
+}
+
+impl From<Rotation3d> for Motion3d {
+    fn from(rotation: Rotation3d) -> Motion3d {
+        Motion3d {
+            rotation,
+            offset: vec3d(0.0, 0.0, 0.0),
+        }
+    }
+}
+
+impl_trans_mul!(Rotation3d, Motion3d);
+
+impl core::ops::Mul<Motion3d> for Motion3d {
+    type Output = Motion3d;
+    fn mul(self, rhs: Motion3d) -> Motion3d {
+        Motion3d {
+            rotation: self.rotation * rhs.rotation,
+            offset: self.rotation * rhs.offset + self.offset,
+        }
+    }
+}
+
+impl core::ops::Mul<Vector3d> for Motion3d {
+    type Output = Vector3d;
+    fn mul(self, rhs: Vector3d) -> Vector3d {
+        self.rotation * rhs + self.offset
+    }
+}
+
+/// A double-precision transform in three-dimensional space consisting of rotation, translation
+/// and uniform scaling. See [`Similarity3`].
+#[repr(C)]
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Similarity3d {
+    /// The rotation component of this transform, applied before translation.
+    pub rotation: Rotation3d,
+
+    /// The scaling component of this transform, applied before translation.
+    pub scaling: f64,
+
+    /// The offset for the translation component of this transform, applied after rotation and
+    /// scaling.
+    pub offset: Vector3d,
+}
+
+impl Similarity3d {
+    /// The identity similarity.
+    pub const fn identity() -> Self {
+        Self {
+            rotation: Rotation3d::IDENTITY,
+            scaling: 1.0,
+            offset: vec3d(0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Gets the inverse of this similarity.
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        let scaling = 1.0 / self.scaling;
+        Self {
+            rotation,
+            scaling,
+            offset: rotation * (-self.offset * scaling),
+        }
+    }
+
+    /// Gets the linear component (consisting of rotation and scaling) for this similarity.
+    pub fn linear(&self) -> Matrix3d {
+        Matrix3d::from(self.rotation) * self.scaling
+    }
+
+    /// Converts this similarity to single precision, rounding to a nearby representable
+    /// similarity.
+    pub fn to_f32(&self) -> Similarity3 {
+        Similarity3 {
+            rotation: self.rotation.to_f32(),
+            scaling: self.scaling as f32,
+            offset: self.offset.to_f32(),
+        }
+    }
+}
+
+impl Default for Similarity3d {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<Similarity3> for Similarity3d {
+    fn from(similarity: Similarity3) -> Self {
+        Self {
+            rotation: similarity.rotation.into(),
+            scaling: similarity.scaling.into(),
+            offset: similarity.offset.into(),
+        }
+    }
+}
+
+impl From<Rotation3d> for Similarity3d {
+    fn from(rotation: Rotation3d) -> Similarity3d {
+        Motion3d::from(rotation).into()
+    }
+}
+
+impl From<Motion3d> for Similarity3d {
+    fn from(motion: Motion3d) -> Similarity3d {
+        Similarity3d {
+            rotation: motion.rotation,
+            scaling: 1.0,
+            offset: motion.offset,
+        }
+    }
+}
+
+impl_trans_mul!(Rotation3d, Similarity3d);
+impl_trans_mul!(Motion3d, Similarity3d);
+
+impl core::ops::Mul<Similarity3d> for Similarity3d {
+    type Output = Similarity3d;
+    fn mul(self, rhs: Similarity3d) -> Similarity3d {
+        Similarity3d {
+            rotation: self.rotation * rhs.rotation,
+            scaling: self.scaling * rhs.scaling,
+            offset: self.rotation * (rhs.offset * self.scaling) + self.offset,
+        }
+    }
+}
+
+impl core::ops::Mul<Vector3d> for Similarity3d {
+    type Output = Vector3d;
+    fn mul(self, rhs: Vector3d) -> Vector3d {
+        self.rotation * (rhs * self.scaling) + self.offset
+    }
+}
+
+/// A double-precision affine transform in three-dimensional space. See [`Affine3`].
+#[repr(C)]
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Affine3d {
+    /// The linear component of this transform, applied before translation.
+    pub linear: Matrix3d,
+
+    /// The offset for the translation component of this transform, applied after the linear
+    /// component.
+    pub offset: Vector3d,
+}
+
+impl Affine3d {
+    /// The identity transform.
+    pub const fn identity() -> Self {
+        Self {
+            linear: Matrix3d::identity(),
+            offset: vec3d(0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Gets the inverse of this affine transform.
+    pub fn inverse(&self) -> Self {
+        let linear = self.linear.inverse();
+        Self {
+            linear,
+            offset: linear * (-self.offset),
+        }
+    }
+
+    /// Converts this transform to single precision, rounding to the nearest representable
+    /// transform.
+    pub fn to_f32(&self) -> Affine3 {
+        Affine3 {
+            linear: self.linear.to_f32(),
+            offset: self.offset.to_f32(),
+        }
+    }
+}
+
+impl Default for Affine3d {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<Affine3> for Affine3d {
+    fn from(affine: Affine3) -> Self {
+        Self {
+            linear: affine.linear.into(),
+            offset: affine.offset.into(),
+        }
+    }
+}
+
+impl From<Rotation3d> for Affine3d {
+    fn from(rotation: Rotation3d) -> Affine3d {
+        Motion3d::from(rotation).into()
+    }
+}
+
+impl From<Motion3d> for Affine3d {
+    fn from(motion: Motion3d) -> Affine3d {
+        Affine3d {
+            linear: Matrix3d::from(motion.rotation),
+            offset: motion.offset,
+        }
+    }
+}
+
+impl From<Similarity3d> for Affine3d {
+    fn from(similarity: Similarity3d) -> Affine3d {
+        Affine3d {
+            linear: similarity.linear(),
+            offset: similarity.offset,
+        }
+    }
+}
+
+impl_trans_mul!(Rotation3d, Affine3d);
+impl_trans_mul!(Motion3d, Affine3d);
+impl_trans_mul!(Similarity3d, Affine3d);
+
+impl core::ops::Mul<Affine3d> for Affine3d {
+    type Output = Affine3d;
+    fn mul(self, rhs: Affine3d) -> Affine3d {
+        Affine3d {
+            linear: self.linear * rhs.linear,
+            offset: self.linear * rhs.offset + self.offset,
+        }
+    }
+}
+
+impl core::ops::Mul<Vector3d> for Affine3d {
+    type Output = Vector3d;
+    fn mul(self, rhs: Vector3d) -> Vector3d {
+        self.linear * rhs + self.offset
+    }
+}
+
+/// A double-precision projective transform in three-dimensional space. See [`Projective3`].
+#[repr(transparent)]
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Projective3d(Matrix4d);
+
+impl Projective3d {
+    /// The identity projective transform.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self(Matrix4d::identity())
+    }
+
+    /// Constructs a projective transform from its 4x4 matrix representation.
+    #[inline]
+    pub const fn new(matrix: Matrix4d) -> Self {
+        Self(matrix)
+    }
+
+    /// Gets the 4x4 matrix representation of this projective transform.
+    #[inline]
+    pub const fn as_matrix(&self) -> &Matrix4d {
+        &self.0
+    }
+
+    /// Gets the inverse of this projective transform.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Self(self.0.inverse())
+    }
+
+    /// Converts this transform to single precision, rounding to the nearest representable
+    /// transform.
+    pub fn to_f32(&self) -> Projective3 {
+        Projective3::new(self.0.to_f32())
+    }
+}
+
+impl From<Projective3> for Projective3d {
+    #[inline]
+    fn from(proj: Projective3) -> Self {
+        Self((*proj.as_matrix()).into())
+    }
+}
+
+impl From<Rotation3d> for Projective3d {
+    #[inline]
+    fn from(rotation: Rotation3d) -> Self {
+        Affine3d::from(rotation).into()
+    }
+}
+
+impl From<Motion3d> for Projective3d {
+    #[inline]
+    fn from(motion: Motion3d) -> Self {
+        Affine3d::from(motion).into()
+    }
+}
+
+impl From<Similarity3d> for Projective3d {
+    #[inline]
+    fn from(similarity: Similarity3d) -> Self {
+        Affine3d::from(similarity).into()
+    }
+}
+
+impl From<Affine3d> for Projective3d {
+    #[inline]
+    fn from(affine: Affine3d) -> Self {
+        Self(Matrix4d {
+            x: vec4d(affine.linear.x.x, affine.linear.x.y, affine.linear.x.z, 0.0),
+            y: vec4d(affine.linear.y.x, affine.linear.y.y, affine.linear.y.z, 0.0),
+            z: vec4d(affine.linear.z.x, affine.linear.z.y, affine.linear.z.z, 0.0),
+            w: vec4d(affine.offset.x, affine.offset.y, affine.offset.z, 1.0),
+        })
+    }
+}
+
+impl_trans_mul!(Rotation3d, Projective3d);
+impl_trans_mul!(Motion3d, Projective3d);
+impl_trans_mul!(Similarity3d, Projective3d);
+impl_trans_mul!(Affine3d, Projective3d);
+
+impl core::ops::Mul<Projective3d> for Projective3d {
+    type Output = Projective3d;
+    #[inline]
+    fn mul(self, rhs: Projective3d) -> Projective3d {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl core::ops::Mul<Vector3d> for Projective3d {
+    type Output = Vector3d;
+    #[inline]
+    fn mul(self, rhs: Vector3d) -> Vector3d {
+        let r = self.0 * vec4d(rhs.x, rhs.y, rhs.z, 1.0);
+        vec3d(r.x, r.y, r.z) / r.w
+    }
+}
+
+impl core::ops::Mul<Vector4d> for Projective3d {
+    type Output = Vector4d;
+    #[inline]
+    fn mul(self, rhs: Vector4d) -> Vector4d {
+        self.0 * rhs
+    }
+}
+
+#[test]
+fn test_camera_relative() {
+    // At this distance from the origin, single precision can't resolve millimeters
+    let camera = Motion3d {
+        rotation: Rotation3d::from_euler(vec3d(0.1, 0.7, -0.2)),
+        offset: vec3d(6.4e6, -2.0e5, 3.1e6),
+    };
+    let point = camera * vec3d(0.0012, -0.0034, -1.0);
+    let relative = (camera.inverse() * Motion3d::translate(point)).to_f32();
+    approx::assert_relative_eq!(
+        relative.offset,
+        crate::vec3(0.0012, -0.0034, -1.0),
+        epsilon = 1e-6
+    );
+
+    // Round-tripping a single-precision transform is lossless
+    let motion = Motion3 {
+        rotation: crate::Rotation3::from_euler(crate::vec3(0.3, -1.2, 0.5)),
+        offset: crate::vec3(1.5, 2.25, -3.0),
+    };
+    assert_eq!(Motion3d::from(motion).to_f32(), motion);
+}
+
+#[test]
+fn test_projective_inverse() {
+    let proj = Projective3d::new(Matrix4d {
+        x: vec4d(1.5, 0.0, 0.0, 0.0),
+        y: vec4d(0.0, 2.0, 0.0, 0.0),
+        z: vec4d(0.25, 0.0, 0.5, -1.0),
+        w: vec4d(1.0, 0.0, 2.0, 0.0),
+    }) * Similarity3d {
+        rotation: Rotation3d::from_euler(vec3d(0.5, 1.0, 0.2)),
+        scaling: 2.0,
+        offset: vec3d(1.0, 2.0, 3.0),
+    };
+    let x = vec3d(0.3, -0.2, 0.7);
+    assert!((proj.inverse() * (proj * x) - x).norm() < 1e-12);
+}
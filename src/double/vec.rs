@@ -0,0 +1,513 @@
+use crate::{math, vec2, vec3, vec4, Matrix2, Matrix3, Matrix4, Vector2, Vector3, Vector4};
+
+/// A double-precision vector in two-dimensional space.
+#[repr(C)]
+#[derive(
+    Default,
+    PartialEq,
+    Copy,
+    Clone,
+    Debug,
+    derive_more::Add,
+    derive_more::Sub,
+    derive_more::Neg,
+    derive_more::Mul,
+    derive_more::Div,
+)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Vector2d {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Constructs a [`Vector2d`] from its components.
+#[inline]
+pub const fn vec2d(x: f64, y: f64) -> Vector2d {
+    Vector2d { x, y }
+}
+
+impl Vector2d {
+    /// Gets the dot product of this vector with another.
+    #[inline]
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Gets the squared length of this vector.
+    #[inline]
+    pub fn norm_squared(&self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Gets the length of this vector.
+    #[inline]
+    pub fn norm(&self) -> f64 {
+        math::sqrt(self.norm_squared())
+    }
+
+    /// Converts this vector to single precision, rounding each component to the nearest
+    /// representable value.
+    #[inline]
+    pub fn to_f32(&self) -> Vector2 {
+        vec2(self.x as f32, self.y as f32)
+    }
+}
+
+impl From<Vector2> for Vector2d {
+    #[inline]
+    fn from(vec: Vector2) -> Self {
+        vec2d(vec.x.into(), vec.y.into())
+    }
+}
+
+/// A double-precision vector in three-dimensional space.
+#[repr(C)]
+#[derive(
+    Default,
+    PartialEq,
+    Copy,
+    Clone,
+    Debug,
+    derive_more::Add,
+    derive_more::Sub,
+    derive_more::Neg,
+    derive_more::Mul,
+    derive_more::Div,
+)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Vector3d {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+/// Constructs a [`Vector3d`] from its components.
+#[inline]
+pub const fn vec3d(x: f64, y: f64, z: f64) -> Vector3d {
+    Vector3d { x, y, z }
+}
+
+impl Vector3d {
+    /// Gets the dot product of this vector with another.
+    #[inline]
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Gets the cross product of this vector with another.
+    #[inline]
+    pub fn cross(&self, other: &Self) -> Self {
+        vec3d(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Gets the squared length of this vector.
+    #[inline]
+    pub fn norm_squared(&self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Gets the length of this vector.
+    #[inline]
+    pub fn norm(&self) -> f64 {
+        math::sqrt(self.norm_squared())
+    }
+
+    /// Converts this vector to single precision, rounding each component to the nearest
+    /// representable value.
+    #[inline]
+    pub fn to_f32(&self) -> Vector3 {
+        vec3(self.x as f32, self.y as f32, self.z as f32)
+    }
+}
+
+impl From<Vector3> for Vector3d {
+    #[inline]
+    fn from(vec: Vector3) -> Self {
+        vec3d(vec.x.into(), vec.y.into(), vec.z.into())
+    }
+}
+
+/// A double-precision vector in four-dimensional space.
+#[repr(C)]
+#[derive(
+    Default,
+    PartialEq,
+    Copy,
+    Clone,
+    Debug,
+    derive_more::Add,
+    derive_more::Sub,
+    derive_more::Neg,
+    derive_more::Mul,
+    derive_more::Div,
+)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Vector4d {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+/// Constructs a [`Vector4d`] from its components.
+#[inline]
+pub const fn vec4d(x: f64, y: f64, z: f64, w: f64) -> Vector4d {
+    Vector4d { x, y, z, w }
+}
+
+impl Vector4d {
+    /// Gets the dot product of this vector with another.
+    #[inline]
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Converts this vector to single precision, rounding each component to the nearest
+    /// representable value.
+    #[inline]
+    pub fn to_f32(&self) -> Vector4 {
+        vec4(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+}
+
+impl From<Vector4> for Vector4d {
+    #[inline]
+    fn from(vec: Vector4) -> Self {
+        vec4d(vec.x.into(), vec.y.into(), vec.z.into(), vec.w.into())
+    }
+}
+
+/// A double-precision 2x2 matrix, stored as columns.
+#[repr(C)]
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Matrix2d {
+    pub x: Vector2d,
+    pub y: Vector2d,
+}
+
+impl Matrix2d {
+    /// The identity matrix.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self {
+            x: vec2d(1.0, 0.0),
+            y: vec2d(0.0, 1.0),
+        }
+    }
+
+    /// Gets the inverse of this matrix.
+    pub fn inverse(&self) -> Self {
+        let det = self.x.x * self.y.y - self.x.y * self.y.x;
+        Self {
+            x: vec2d(self.y.y, -self.x.y) / det,
+            y: vec2d(-self.y.x, self.x.x) / det,
+        }
+    }
+
+    /// Converts this matrix to single precision, rounding each component to the nearest
+    /// representable value.
+    pub fn to_f32(&self) -> Matrix2 {
+        Matrix2 {
+            x: self.x.to_f32(),
+            y: self.y.to_f32(),
+        }
+    }
+}
+
+impl From<Matrix2> for Matrix2d {
+    #[inline]
+    fn from(mat: Matrix2) -> Self {
+        Self {
+            x: mat.x.into(),
+            y: mat.y.into(),
+        }
+    }
+}
+
+impl core::ops::Mul<Vector2d> for Matrix2d {
+    type Output = Vector2d;
+    #[inline]
+    fn mul(self, rhs: Vector2d) -> Vector2d {
+        self.x * rhs.x + self.y * rhs.y
+    }
+}
+
+impl core::ops::Mul<f64> for Matrix2d {
+    type Output = Matrix2d;
+    #[inline]
+    fn mul(self, rhs: f64) -> Matrix2d {
+        Matrix2d {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl core::ops::Mul<Matrix2d> for Matrix2d {
+    type Output = Matrix2d;
+    #[inline]
+    fn mul(self, rhs: Matrix2d) -> Matrix2d {
+        Matrix2d {
+            x: self * rhs.x,
+            y: self * rhs.y,
+        }
+    }
+}
+
+/// A double-precision 3x3 matrix, stored as columns.
+#[repr(C)]
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Matrix3d {
+    pub x: Vector3d,
+    pub y: Vector3d,
+    pub z: Vector3d,
+}
+
+impl Matrix3d {
+    /// The identity matrix.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self {
+            x: vec3d(1.0, 0.0, 0.0),
+            y: vec3d(0.0, 1.0, 0.0),
+            z: vec3d(0.0, 0.0, 1.0),
+        }
+    }
+
+    /// Gets the transpose of this matrix.
+    pub fn transpose(&self) -> Self {
+        Self {
+            x: vec3d(self.x.x, self.y.x, self.z.x),
+            y: vec3d(self.x.y, self.y.y, self.z.y),
+            z: vec3d(self.x.z, self.y.z, self.z.z),
+        }
+    }
+
+    /// Gets the inverse of this matrix.
+    pub fn inverse(&self) -> Self {
+        // The rows of the inverse are the cross products of pairs of columns
+        let x = self.y.cross(&self.z);
+        let y = self.z.cross(&self.x);
+        let z = self.x.cross(&self.y);
+        let det = self.x.dot(&x);
+        Self { x, y, z }.transpose() * (1.0 / det)
+    }
+
+    /// Converts this matrix to single precision, rounding each component to the nearest
+    /// representable value.
+    pub fn to_f32(&self) -> Matrix3 {
+        Matrix3 {
+            x: self.x.to_f32(),
+            y: self.y.to_f32(),
+            z: self.z.to_f32(),
+        }
+    }
+}
+
+impl From<Matrix3> for Matrix3d {
+    #[inline]
+    fn from(mat: Matrix3) -> Self {
+        Self {
+            x: mat.x.into(),
+            y: mat.y.into(),
+            z: mat.z.into(),
+        }
+    }
+}
+
+impl core::ops::Mul<Vector3d> for Matrix3d {
+    type Output = Vector3d;
+    #[inline]
+    fn mul(self, rhs: Vector3d) -> Vector3d {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+}
+
+impl core::ops::Mul<f64> for Matrix3d {
+    type Output = Matrix3d;
+    #[inline]
+    fn mul(self, rhs: f64) -> Matrix3d {
+        Matrix3d {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl core::ops::Mul<Matrix3d> for Matrix3d {
+    type Output = Matrix3d;
+    #[inline]
+    fn mul(self, rhs: Matrix3d) -> Matrix3d {
+        Matrix3d {
+            x: self * rhs.x,
+            y: self * rhs.y,
+            z: self * rhs.z,
+        }
+    }
+}
+
+/// A double-precision 4x4 matrix, stored as columns.
+#[repr(C)]
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Matrix4d {
+    pub x: Vector4d,
+    pub y: Vector4d,
+    pub z: Vector4d,
+    pub w: Vector4d,
+}
+
+impl Matrix4d {
+    /// The identity matrix.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self {
+            x: vec4d(1.0, 0.0, 0.0, 0.0),
+            y: vec4d(0.0, 1.0, 0.0, 0.0),
+            z: vec4d(0.0, 0.0, 1.0, 0.0),
+            w: vec4d(0.0, 0.0, 0.0, 1.0),
+        }
+    }
+
+    /// Gets the columns of this matrix as arrays.
+    fn to_arrays(self) -> [[f64; 4]; 4] {
+        [self.x, self.y, self.z, self.w].map(|c| [c.x, c.y, c.z, c.w])
+    }
+
+    /// Constructs a matrix from arrays of its columns.
+    fn from_arrays(cols: [[f64; 4]; 4]) -> Self {
+        let [x, y, z, w] = cols.map(|[x, y, z, w]| vec4d(x, y, z, w));
+        Self { x, y, z, w }
+    }
+
+    /// Gets the inverse of this matrix.
+    pub fn inverse(&self) -> Self {
+        // Use Gauss-Jordan elimination with partial pivoting. Since `(A⁻¹)ᵀ = (Aᵀ)⁻¹`, the
+        // column-major arrays can be treated as the rows of the transpose.
+        let mut a = self.to_arrays();
+        let mut inv = Self::identity().to_arrays();
+        for col in 0..4 {
+            let pivot = (col..4).fold(col, |best, row| {
+                if a[row][col].abs() > a[best][col].abs() {
+                    row
+                } else {
+                    best
+                }
+            });
+            a.swap(col, pivot);
+            inv.swap(col, pivot);
+            let scale = 1.0 / a[col][col];
+            a[col] = a[col].map(|v| v * scale);
+            inv[col] = inv[col].map(|v| v * scale);
+            let (a_col, inv_col) = (a[col], inv[col]);
+            for (row, (a_row, inv_row)) in a.iter_mut().zip(inv.iter_mut()).enumerate() {
+                if row != col {
+                    let factor = a_row[col];
+                    for (value, col_value) in a_row.iter_mut().zip(a_col) {
+                        *value -= factor * col_value;
+                    }
+                    for (value, col_value) in inv_row.iter_mut().zip(inv_col) {
+                        *value -= factor * col_value;
+                    }
+                }
+            }
+        }
+        Self::from_arrays(inv)
+    }
+
+    /// Converts this matrix to single precision, rounding each component to the nearest
+    /// representable value.
+    pub fn to_f32(&self) -> Matrix4 {
+        Matrix4 {
+            x: self.x.to_f32(),
+            y: self.y.to_f32(),
+            z: self.z.to_f32(),
+            w: self.w.to_f32(),
+        }
+    }
+}
+
+impl From<Matrix4> for Matrix4d {
+    #[inline]
+    fn from(mat: Matrix4) -> Self {
+        Self {
+            x: mat.x.into(),
+            y: mat.y.into(),
+            z: mat.z.into(),
+            w: mat.w.into(),
+        }
+    }
+}
+
+impl core::ops::Mul<Vector4d> for Matrix4d {
+    type Output = Vector4d;
+    #[inline]
+    fn mul(self, rhs: Vector4d) -> Vector4d {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+}
+
+impl core::ops::Mul<f64> for Matrix4d {
+    type Output = Matrix4d;
+    #[inline]
+    fn mul(self, rhs: f64) -> Matrix4d {
+        Matrix4d {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+            w: self.w * rhs,
+        }
+    }
+}
+
+impl core::ops::Mul<Matrix4d> for Matrix4d {
+    type Output = Matrix4d;
+    #[inline]
+    fn mul(self, rhs: Matrix4d) -> Matrix4d {
+        Matrix4d {
+            x: self * rhs.x,
+            y: self * rhs.y,
+            z: self * rhs.z,
+            w: self * rhs.w,
+        }
+    }
+}
+
+#[test]
+fn test_inverse() {
+    let mat = Matrix3d {
+        x: vec3d(1.0, 0.5, 0.0),
+        y: vec3d(-0.2, 2.0, 0.3),
+        z: vec3d(0.1, 0.0, 0.7),
+    };
+    let x = vec3d(0.3, -0.7, 1.1);
+    assert!((mat.inverse() * (mat * x) - x).norm() < 1e-12);
+    let mat = Matrix4d {
+        x: vec4d(0.0, 1.5, 0.0, 0.0),
+        y: vec4d(2.0, 0.0, 0.0, 0.0),
+        z: vec4d(0.25, 0.0, 0.5, -1.0),
+        w: vec4d(1.0, 0.0, 2.0, 0.0),
+    };
+    let x = vec4d(0.3, -0.7, 1.1, 1.0);
+    let y = mat.inverse() * (mat * x) - x;
+    assert!(y.dot(&y) < 1e-24);
+}
@@ -0,0 +1,213 @@
+use super::{check_lengths, valid_weights, weight, FitPoints, FitResult};
+use crate::{math, vec2, Affine2, Matrix2, Motion2, Rotation2, Scalar, Similarity2, Vector2};
+
+/// The weighted statistics of pairs of points needed to fit two-dimensional transforms to them.
+struct Moments {
+    /// The weighted centroid of the source points.
+    source_mean: Vector2,
+
+    /// The weighted centroid of the target points.
+    target_mean: Vector2,
+
+    /// The weighted sum of the outer products `b * aᵀ` of the centered target and source points.
+    ba: Matrix2,
+
+    /// The weighted sum of the outer products `a * aᵀ` of the centered source points.
+    aa: Matrix2,
+}
+
+impl Moments {
+    /// Computes the [`Moments`] for the given pairs of points, or returns [`None`] if any weight
+    /// is negative or the total weight is not positive.
+    fn new(source: &[Vector2], target: &[Vector2], weights: Option<&[Scalar]>) -> Option<Self> {
+        check_lengths(source, target, weights);
+        if !valid_weights(weights) {
+            return None;
+        }
+        let mut total_weight = 0.0;
+        let mut source_sum = vec2(0.0, 0.0);
+        let mut target_sum = vec2(0.0, 0.0);
+        for (i, (a, b)) in source.iter().zip(target.iter()).enumerate() {
+            let weight = weight(weights, i);
+            total_weight += weight;
+            source_sum = source_sum + *a * weight;
+            target_sum = target_sum + *b * weight;
+        }
+        if total_weight <= 0.0 {
+            return None;
+        }
+        let source_mean = source_sum / total_weight;
+        let target_mean = target_sum / total_weight;
+        let zero = vec2(0.0, 0.0);
+        let (mut ba_x, mut ba_y, mut aa_x, mut aa_y) = (zero, zero, zero, zero);
+        for (i, (a, b)) in source.iter().zip(target.iter()).enumerate() {
+            let weight = weight(weights, i);
+            let a = *a - source_mean;
+            let b = *b - target_mean;
+            ba_x = ba_x + b * (weight * a.x);
+            ba_y = ba_y + b * (weight * a.y);
+            aa_x = aa_x + a * (weight * a.x);
+            aa_y = aa_y + a * (weight * a.y);
+        }
+        Some(Self {
+            source_mean,
+            target_mean,
+            ba: Matrix2 { x: ba_x, y: ba_y },
+            aa: Matrix2 { x: aa_x, y: aa_y },
+        })
+    }
+
+    /// Gets the weighted sums of `a · b` and `a × b` over the centered pairs of points. These are
+    /// proportional to the cosine and sine of the best-fitting rotation angle.
+    fn dot_cross(&self) -> (Scalar, Scalar) {
+        let ba = &self.ba;
+        (ba.x.x + ba.y.y, ba.x.y - ba.y.x)
+    }
+}
+
+/// Gets the rotation with the given cosine and sine, scaled by some unknown non-negative factor.
+fn rotation_from_cos_sin(cos: Scalar, sin: Scalar) -> Rotation2 {
+    let norm = math::sqrt(cos * cos + sin * sin);
+    if norm == 0.0 {
+        Rotation2::IDENTITY
+    } else if cos >= 0.0 {
+        Rotation2::from_tan_half_angle(sin / (norm + cos))
+    } else {
+        Rotation2::from_tan_half_angle((norm - cos) / sin)
+    }
+}
+
+impl FitPoints for Motion2 {
+    type Point = Vector2;
+    fn fit(
+        source: &[Vector2],
+        target: &[Vector2],
+        weights: Option<&[Scalar]>,
+    ) -> Option<FitResult<Self>> {
+        let moments = Moments::new(source, target, weights)?;
+        let (dot, cross) = moments.dot_cross();
+        let rotation = rotation_from_cos_sin(dot, cross);
+        let motion = Motion2 {
+            rotation,
+            offset: moments.target_mean - rotation * moments.source_mean,
+        };
+        Some(FitResult::new(motion, source, target, weights, dist_sqr))
+    }
+}
+
+impl FitPoints for Similarity2 {
+    type Point = Vector2;
+    fn fit(
+        source: &[Vector2],
+        target: &[Vector2],
+        weights: Option<&[Scalar]>,
+    ) -> Option<FitResult<Self>> {
+        let moments = Moments::new(source, target, weights)?;
+        let spread = moments.aa.x.x + moments.aa.y.y;
+        if spread <= 0.0 {
+            return None;
+        }
+        let (dot, cross) = moments.dot_cross();
+        let rotation = rotation_from_cos_sin(dot, cross);
+        let scaling = math::sqrt(dot * dot + cross * cross) / spread;
+        let similarity = Similarity2 {
+            rotation,
+            scaling,
+            offset: moments.target_mean - rotation * moments.source_mean * scaling,
+        };
+        Some(FitResult::new(similarity, source, target, weights, dist_sqr))
+    }
+}
+
+impl FitPoints for Affine2 {
+    type Point = Vector2;
+    fn fit(
+        source: &[Vector2],
+        target: &[Vector2],
+        weights: Option<&[Scalar]>,
+    ) -> Option<FitResult<Self>> {
+        let moments = Moments::new(source, target, weights)?;
+        let aa = &moments.aa;
+        let trace = aa.x.x + aa.y.y;
+        let det = aa.x.x * aa.y.y - aa.x.y * aa.y.x;
+        if det <= 1e-6 * trace * trace {
+            return None;
+        }
+        let linear = moments.ba * aa.inverse();
+        let affine = Affine2 {
+            linear,
+            offset: moments.target_mean - linear * moments.source_mean,
+        };
+        Some(FitResult::new(affine, source, target, weights, dist_sqr))
+    }
+}
+
+/// Gets the squared distance between the transformed source point and the target point.
+#[inline]
+fn dist_sqr<T: core::ops::Mul<Vector2, Output = Vector2> + Copy>(
+    trans: &T,
+    source: Vector2,
+    target: Vector2,
+) -> Scalar {
+    (*trans * source - target).norm_squared()
+}
+
+#[test]
+fn test_fit_motion2() {
+    for angle in [0.0, 0.7, -2.0, crate::PI] {
+        let motion = Motion2 {
+            rotation: Rotation2::from_angle(angle),
+            offset: vec2(1.0, -3.0),
+        };
+        let source = [
+            vec2(0.0, 0.0),
+            vec2(1.0, 0.5),
+            vec2(0.0, 2.0),
+            vec2(-1.0, 3.0),
+            vec2(2.0, -1.0),
+        ];
+        let target: Vec<_> = source.iter().map(|p| motion * *p).collect();
+        let res = Motion2::fit(&source, &target, None).unwrap();
+        assert!(res.rms_error < 1e-4 && res.max_error < 1e-4);
+    }
+
+    // A two-finger gesture, where the fingers move apart and rotate
+    let source = [vec2(0.0, 0.0), vec2(1.0, 0.0)];
+    let target = [vec2(1.0, 1.0), vec2(1.0, 3.0)];
+    let res = Motion2::fit(&source, &target, None).unwrap();
+    approx::assert_relative_eq!(res.transform * vec2(0.5, 0.0), vec2(1.0, 2.0), epsilon = 1e-5);
+    approx::assert_relative_eq!(res.rms_error, 0.5, epsilon = 1e-5);
+    let res = Similarity2::fit(&source, &target, None).unwrap();
+    approx::assert_relative_eq!(res.transform.scaling, 2.0, epsilon = 1e-5);
+    assert!(res.rms_error < 1e-5);
+}
+
+#[test]
+fn test_fit_affine2() {
+    let affine = Affine2 {
+        linear: Matrix2 {
+            x: vec2(1.0, 0.5),
+            y: vec2(-0.2, 2.0),
+        },
+        offset: vec2(1.0, -3.0),
+    };
+    let source = [
+        vec2(0.0, 0.0),
+        vec2(1.0, 0.5),
+        vec2(0.0, 2.0),
+        vec2(-1.0, 3.0),
+        vec2(2.0, -1.0),
+    ];
+    let target: Vec<_> = source.iter().map(|p| affine * *p).collect();
+    let res = Affine2::fit(&source, &target, None).unwrap();
+    assert!(res.rms_error < 1e-4);
+
+    // Collinear points don't determine an affine transform
+    let collinear: Vec<_> = source.iter().map(|p| vec2(p.x, 2.0 * p.x)).collect();
+    assert!(Affine2::fit(&collinear, &target, None).is_none());
+
+    // Negative weights are rejected, even if the total weight is positive
+    let weights = [1.0, 1.0, -0.5, 1.0, 1.0];
+    assert!(Affine2::fit(&source, &target, Some(&weights)).is_none());
+    assert!(Motion2::fit(&source, &target, Some(&weights)).is_none());
+}
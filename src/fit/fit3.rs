@@ -0,0 +1,288 @@
+use super::{check_lengths, valid_weights, weight, FitPoints, FitResult};
+use crate::{math, vec3, Affine3, Matrix3, Motion3, Rotation3, Scalar, Similarity3, Vector3};
+
+/// Gets the weighted centroids of the source and target points, or returns [`None`] if any weight
+/// is negative or the total weight is not positive.
+fn centroids(
+    source: &[Vector3],
+    target: &[Vector3],
+    weights: Option<&[Scalar]>,
+) -> Option<(Vector3, Vector3)> {
+    check_lengths(source, target, weights);
+    if !valid_weights(weights) {
+        return None;
+    }
+    let mut total_weight = 0.0;
+    let mut source_sum = vec3(0.0, 0.0, 0.0);
+    let mut target_sum = vec3(0.0, 0.0, 0.0);
+    for (i, (a, b)) in source.iter().zip(target.iter()).enumerate() {
+        let weight = weight(weights, i);
+        total_weight += weight;
+        source_sum = source_sum + *a * weight;
+        target_sum = target_sum + *b * weight;
+    }
+    if total_weight > 0.0 {
+        Some((source_sum / total_weight, target_sum / total_weight))
+    } else {
+        None
+    }
+}
+
+/// Gets the weighted sums of the outer products `b * aᵀ` of the centered target and source points,
+/// and of the centered source points with themselves.
+fn covariances(
+    source: &[Vector3],
+    target: &[Vector3],
+    weights: Option<&[Scalar]>,
+    source_mean: Vector3,
+    target_mean: Vector3,
+) -> (Matrix3, Matrix3) {
+    let zero = vec3(0.0, 0.0, 0.0);
+    let mut ba = [zero; 3];
+    let mut aa = [zero; 3];
+    for (i, (a, b)) in source.iter().zip(target.iter()).enumerate() {
+        let weight = weight(weights, i);
+        let a = *a - source_mean;
+        let b = *b - target_mean;
+        for (j, a_j) in [a.x, a.y, a.z].into_iter().enumerate() {
+            ba[j] = ba[j] + b * (weight * a_j);
+            aa[j] = aa[j] + a * (weight * a_j);
+        }
+    }
+    let [x, y, z] = ba;
+    let ba = Matrix3 { x, y, z };
+    let [x, y, z] = aa;
+    let aa = Matrix3 { x, y, z };
+    (ba, aa)
+}
+
+/// Finds the rotation `R` which maximizes the weighted sum of `b · (R * a)` over centered pairs of
+/// source and target points, given the weighted sum of their outer products `b * aᵀ`, using Horn's
+/// quaternion method. The result is always a proper rotation, even when a reflection would fit the
+/// points better.
+fn best_rotation(ba: &Matrix3) -> Rotation3 {
+    // `s_ij` is the sum of products of the `i` component of the source points and the `j`
+    // component of the target points
+    let (s_xx, s_xy, s_xz) = (ba.x.x, ba.x.y, ba.x.z);
+    let (s_yx, s_yy, s_yz) = (ba.y.x, ba.y.y, ba.y.z);
+    let (s_zx, s_zy, s_zz) = (ba.z.x, ba.z.y, ba.z.z);
+    let n = [
+        [s_xx + s_yy + s_zz, s_yz - s_zy, s_zx - s_xz, s_xy - s_yx],
+        [s_yz - s_zy, s_xx - s_yy - s_zz, s_xy + s_yx, s_zx + s_xz],
+        [s_zx - s_xz, s_xy + s_yx, -s_xx + s_yy - s_zz, s_yz + s_zy],
+        [s_xy - s_yx, s_zx + s_xz, s_yz + s_zy, -s_xx - s_yy + s_zz],
+    ];
+    let [w, x, y, z] = max_eigenvector(n);
+    let norm = math::sqrt(w * w + x * x + y * y + z * z);
+    Rotation3::new_unchecked(w / norm, x / norm, y / norm, z / norm)
+}
+
+/// Gets the eigenvector corresponding to the largest eigenvalue of a symmetric 4×4 matrix, using
+/// the Jacobi eigenvalue algorithm.
+fn max_eigenvector(mut a: [[Scalar; 4]; 4]) -> [Scalar; 4] {
+    let mut v = [[0.0; 4]; 4];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+    for _ in 0..32 {
+        let mut changed = false;
+        for p in 0..3 {
+            for q in (p + 1)..4 {
+                let a_pq = a[p][q];
+                let scale = a[p][p].abs() + a[q][q].abs();
+                if a_pq.abs() <= Scalar::EPSILON * 1e-2 * scale {
+                    continue;
+                }
+                changed = true;
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a_pq);
+                let t = theta.signum() / (theta.abs() + math::sqrt(theta * theta + 1.0));
+                let c = 1.0 / math::sqrt(t * t + 1.0);
+                let s = t * c;
+                for row in a.iter_mut().chain(v.iter_mut()) {
+                    let (r_p, r_q) = (row[p], row[q]);
+                    row[p] = c * r_p - s * r_q;
+                    row[q] = s * r_p + c * r_q;
+                }
+                let (a_p, a_q) = (a[p], a[q]);
+                a[p] = core::array::from_fn(|k| c * a_p[k] - s * a_q[k]);
+                a[q] = core::array::from_fn(|k| s * a_p[k] + c * a_q[k]);
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    let best = (1..4).fold(0, |best, i| if a[i][i] > a[best][best] { i } else { best });
+    v.map(|row| row[best])
+}
+
+impl FitPoints for Motion3 {
+    type Point = Vector3;
+    fn fit(
+        source: &[Vector3],
+        target: &[Vector3],
+        weights: Option<&[Scalar]>,
+    ) -> Option<FitResult<Self>> {
+        let (source_mean, target_mean) = centroids(source, target, weights)?;
+        let (ba, _) = covariances(source, target, weights, source_mean, target_mean);
+        let rotation = best_rotation(&ba);
+        let motion = Motion3 {
+            rotation,
+            offset: target_mean - rotation * source_mean,
+        };
+        Some(FitResult::new(motion, source, target, weights, dist_sqr))
+    }
+}
+
+impl FitPoints for Similarity3 {
+    type Point = Vector3;
+    fn fit(
+        source: &[Vector3],
+        target: &[Vector3],
+        weights: Option<&[Scalar]>,
+    ) -> Option<FitResult<Self>> {
+        let (source_mean, target_mean) = centroids(source, target, weights)?;
+        let (ba, aa) = covariances(source, target, weights, source_mean, target_mean);
+        let rotation = best_rotation(&ba);
+        let spread = aa.x.x + aa.y.y + aa.z.z;
+        if spread <= 0.0 {
+            return None;
+        }
+
+        // The optimal scaling is the weighted sum of `b · (R * a)` divided by that of `a · a`
+        let rot = Matrix3::from(rotation);
+        let scaling = (rot.x.dot(&ba.x) + rot.y.dot(&ba.y) + rot.z.dot(&ba.z)) / spread;
+        let similarity = Similarity3 {
+            rotation,
+            scaling,
+            offset: target_mean - rotation * source_mean * scaling,
+        };
+        Some(FitResult::new(similarity, source, target, weights, dist_sqr))
+    }
+}
+
+impl FitPoints for Affine3 {
+    type Point = Vector3;
+    fn fit(
+        source: &[Vector3],
+        target: &[Vector3],
+        weights: Option<&[Scalar]>,
+    ) -> Option<FitResult<Self>> {
+        let (source_mean, target_mean) = centroids(source, target, weights)?;
+        let (ba, aa) = covariances(source, target, weights, source_mean, target_mean);
+        let trace = aa.x.x + aa.y.y + aa.z.z;
+        let det = aa.x.dot(&aa.y.cross(&aa.z));
+        if det <= 1e-6 * trace * trace * trace {
+            return None;
+        }
+        let linear = ba * aa.inverse();
+        let affine = Affine3 {
+            linear,
+            offset: target_mean - linear * source_mean,
+        };
+        Some(FitResult::new(affine, source, target, weights, dist_sqr))
+    }
+}
+
+/// Gets the squared distance between the transformed source point and the target point.
+#[inline]
+fn dist_sqr<T: core::ops::Mul<Vector3, Output = Vector3> + Copy>(
+    trans: &T,
+    source: Vector3,
+    target: Vector3,
+) -> Scalar {
+    (*trans * source - target).norm_squared()
+}
+
+#[test]
+fn test_fit_motion3() {
+    let motion = Motion3 {
+        rotation: Rotation3::from_euler(vec3(0.5, -2.1, 0.7)),
+        offset: vec3(1.0, -3.0, 2.0),
+    };
+    let source = [
+        vec3(0.0, 0.0, 0.0),
+        vec3(1.0, 0.0, 0.5),
+        vec3(0.0, 2.0, -1.0),
+        vec3(-1.0, 0.5, 3.0),
+        vec3(2.0, -1.0, 1.0),
+        vec3(0.3, 0.7, -0.2),
+    ];
+    let target: Vec<_> = source.iter().map(|p| motion * *p).collect();
+    let res = Motion3::fit(&source, &target, None).unwrap();
+    assert!(res.rms_error < 1e-4 && res.max_error < 1e-4);
+    for p in source.iter() {
+        approx::assert_relative_eq!(res.transform * *p, motion * *p, epsilon = 1e-4);
+    }
+
+    // An outlier with zero weight should be ignored
+    let mut target = target;
+    target[2] = vec3(10.0, 10.0, 10.0);
+    let weights = [1.0, 1.0, 0.0, 1.0, 1.0, 1.0];
+    let res = Motion3::fit(&source, &target, Some(&weights)).unwrap();
+    assert!(res.rms_error < 1e-4);
+    approx::assert_relative_eq!(res.transform * source[2], motion * source[2], epsilon = 1e-4);
+
+    // When the target is a reflection of the source, the result should still be a rotation
+    let target: Vec<_> = source.iter().map(|p| vec3(-p.x, p.y, p.z)).collect();
+    let res = Motion3::fit(&source, &target, None).unwrap();
+    let linear = Matrix3::from(res.transform.rotation);
+    assert!(linear.x.dot(&linear.y.cross(&linear.z)) > 0.0);
+    assert!(res.rms_error > 0.1);
+}
+
+#[test]
+fn test_fit_similarity3() {
+    let similarity = Similarity3 {
+        rotation: Rotation3::from_euler(vec3(-0.3, 0.2, 2.5)),
+        scaling: 2.5,
+        offset: vec3(1.0, -3.0, 2.0),
+    };
+    let source = [
+        vec3(0.0, 0.0, 0.0),
+        vec3(1.0, 0.0, 0.5),
+        vec3(0.0, 2.0, -1.0),
+        vec3(-1.0, 0.5, 3.0),
+        vec3(2.0, -1.0, 1.0),
+        vec3(0.3, 0.7, -0.2),
+    ];
+    let target: Vec<_> = source.iter().map(|p| similarity * *p).collect();
+    let res = Similarity3::fit(&source, &target, None).unwrap();
+    assert!(res.rms_error < 1e-3);
+    approx::assert_relative_eq!(res.transform.scaling, 2.5, epsilon = 1e-4);
+}
+
+#[test]
+fn test_fit_affine3() {
+    let affine = Affine3 {
+        linear: Matrix3 {
+            x: vec3(1.0, 0.5, 0.0),
+            y: vec3(-0.2, 2.0, 0.3),
+            z: vec3(0.1, 0.0, 0.7),
+        },
+        offset: vec3(1.0, -3.0, 2.0),
+    };
+    let source = [
+        vec3(0.0, 0.0, 0.0),
+        vec3(1.0, 0.0, 0.5),
+        vec3(0.0, 2.0, -1.0),
+        vec3(-1.0, 0.5, 3.0),
+        vec3(2.0, -1.0, 1.0),
+        vec3(0.3, 0.7, -0.2),
+    ];
+    let target: Vec<_> = source.iter().map(|p| affine * *p).collect();
+    let res = Affine3::fit(&source, &target, None).unwrap();
+    assert!(res.rms_error < 1e-3);
+    for p in source.iter() {
+        approx::assert_relative_eq!(res.transform * *p, affine * *p, epsilon = 1e-3);
+    }
+
+    // Coplanar points don't determine an affine transform
+    let coplanar: Vec<_> = source.iter().map(|p| vec3(p.x, p.y, 0.0)).collect();
+    assert!(Affine3::fit(&coplanar, &target, None).is_none());
+
+    // Negative weights are rejected, even if the total weight is positive
+    let weights = [1.0, 1.0, 1.0, -0.5, 1.0, 1.0];
+    assert!(Affine3::fit(&source, &target, Some(&weights)).is_none());
+    assert!(Motion3::fit(&source, &target, Some(&weights)).is_none());
+}
@@ -0,0 +1,297 @@
+use super::kd_tree::KdTree;
+use super::FitPoints;
+use crate::{math, Motion3, Rotation3, Scalar, Vector3};
+use alloc::vec::Vec;
+
+/// The error metric minimized by [`icp`].
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum IcpMetric<'a> {
+    /// Minimizes the distances between source points and their nearest target points.
+    PointToPoint,
+
+    /// Minimizes the distances between source points and the tangent planes at their nearest
+    /// target points. This typically converges much faster than [`IcpMetric::PointToPoint`] for
+    /// smooth surfaces. The given slice contains the unit normal for each target point.
+    PointToPlane(&'a [Vector3]),
+}
+
+/// The parameters for [`icp`].
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct IcpParams<'a> {
+    /// The error metric to minimize.
+    pub metric: IcpMetric<'a>,
+
+    /// The maximum number of iterations to perform.
+    pub max_iterations: usize,
+
+    /// The algorithm is considered to have converged when the RMS error changes by no more than
+    /// this amount between iterations.
+    pub tolerance: Scalar,
+
+    /// Source points whose nearest target point is further than this distance are ignored.
+    pub max_correspondence_dist: Scalar,
+}
+
+impl Default for IcpParams<'_> {
+    fn default() -> Self {
+        Self {
+            metric: IcpMetric::PointToPoint,
+            max_iterations: 50,
+            tolerance: 1e-6,
+            max_correspondence_dist: Scalar::INFINITY,
+        }
+    }
+}
+
+/// Describes how [`icp`] terminated.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IcpStatus {
+    /// The RMS error stopped changing, within the requested tolerance.
+    #[cfg_attr(feature = "serde", serde(rename = "converged"))]
+    Converged,
+
+    /// The maximum number of iterations was reached before convergence.
+    #[cfg_attr(feature = "serde", serde(rename = "max_iterations"))]
+    MaxIterations,
+
+    /// No source point had a target point within the maximum correspondence distance, or the
+    /// correspondences did not determine a unique transform.
+    #[cfg_attr(feature = "serde", serde(rename = "degenerate"))]
+    Degenerate,
+}
+
+/// The result of [`icp`].
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IcpResult {
+    /// The transform which best aligns the source points with the target points.
+    pub transform: Motion3,
+
+    /// The root-mean-square error, according to the chosen metric, for the final transform.
+    pub rms_error: Scalar,
+
+    /// The number of iterations performed.
+    pub iterations: usize,
+
+    /// How the algorithm terminated.
+    pub status: IcpStatus,
+}
+
+/// Finds the rigid transform which aligns the `source` point cloud with the `target` point cloud,
+/// without known correspondences, using the Iterative Closest Point algorithm starting from the
+/// given `initial` transform.
+///
+/// ICP only finds a local minimum, so `initial` should be reasonably close to the true alignment.
+///
+/// # Panics
+/// Panics if the metric is [`IcpMetric::PointToPlane`] and its normals don't have the same length
+/// as `target`.
+pub fn icp(
+    source: &[Vector3],
+    target: &[Vector3],
+    initial: Motion3,
+    params: &IcpParams,
+) -> IcpResult {
+    if let IcpMetric::PointToPlane(normals) = params.metric {
+        assert_eq!(target.len(), normals.len(), "normals length differs");
+    }
+    let tree = KdTree::new(target);
+    let max_dist_sqr = params.max_correspondence_dist * params.max_correspondence_dist;
+    let mut transform = initial;
+    let mut prev_error = Scalar::INFINITY;
+    let mut iterations = 0;
+    let mut status = IcpStatus::MaxIterations;
+    let mut pairs = Vec::new();
+    let rms_error = loop {
+        // Find correspondences for the current transform
+        pairs.clear();
+        for point in source.iter() {
+            let point = transform * *point;
+            if let Some((index, dist_sqr)) = tree.nearest(point) {
+                if dist_sqr <= max_dist_sqr {
+                    pairs.push((point, index));
+                }
+            }
+        }
+        if pairs.is_empty() {
+            status = IcpStatus::Degenerate;
+            break Scalar::INFINITY;
+        }
+        let error = rms_error(&pairs, target, params.metric);
+        if (prev_error - error).abs() <= params.tolerance {
+            status = IcpStatus::Converged;
+            break error;
+        }
+        if iterations >= params.max_iterations {
+            break error;
+        }
+        prev_error = error;
+        iterations += 1;
+
+        // Find the incremental transform which best aligns the correspondences
+        let delta = match params.metric {
+            IcpMetric::PointToPoint => point_to_point_step(&pairs, target),
+            IcpMetric::PointToPlane(normals) => point_to_plane_step(&pairs, target, normals),
+        };
+        let Some(delta) = delta else {
+            status = IcpStatus::Degenerate;
+            break error;
+        };
+        transform = delta * transform;
+    };
+    IcpResult {
+        transform,
+        rms_error,
+        iterations,
+        status,
+    }
+}
+
+/// Computes the RMS error for the given correspondences, according to the given metric.
+fn rms_error(pairs: &[(Vector3, usize)], target: &[Vector3], metric: IcpMetric) -> Scalar {
+    let total: Scalar = pairs
+        .iter()
+        .map(|(point, index)| {
+            let diff = *point - target[*index];
+            match metric {
+                IcpMetric::PointToPoint => diff.norm_squared(),
+                IcpMetric::PointToPlane(normals) => {
+                    let dist = diff.dot(&normals[*index]);
+                    dist * dist
+                }
+            }
+        })
+        .sum();
+    math::sqrt(total / pairs.len() as Scalar)
+}
+
+/// Finds the transform which minimizes the point-to-point error for the given correspondences.
+fn point_to_point_step(pairs: &[(Vector3, usize)], target: &[Vector3]) -> Option<Motion3> {
+    let source: Vec<_> = pairs.iter().map(|(point, _)| *point).collect();
+    let target: Vec<_> = pairs.iter().map(|(_, index)| target[*index]).collect();
+    Motion3::fit(&source, &target, None).map(|res| res.transform)
+}
+
+/// Finds the transform which minimizes the linearized point-to-plane error for the given
+/// correspondences.
+fn point_to_plane_step(
+    pairs: &[(Vector3, usize)],
+    target: &[Vector3],
+    normals: &[Vector3],
+) -> Option<Motion3> {
+    // Each correspondence contributes a residual `a · x + b`, where `x` contains the small-angle
+    // rotation vector followed by the translation
+    let mut ata = [[0.0; 6]; 6];
+    let mut atb = [0.0; 6];
+    for (point, index) in pairs.iter() {
+        let normal = normals[*index];
+        let c = point.cross(&normal);
+        let a = [c.x, c.y, c.z, normal.x, normal.y, normal.z];
+        let b = (*point - target[*index]).dot(&normal);
+        for (i, a_i) in a.iter().enumerate() {
+            for (j, a_j) in a.iter().enumerate() {
+                ata[i][j] += a_i * a_j;
+            }
+            atb[i] -= a_i * b;
+        }
+    }
+    let [r_x, r_y, r_z, t_x, t_y, t_z] = solve6(ata, atb)?;
+    Some(Motion3 {
+        rotation: Rotation3::from_euler(crate::vec3(r_x, r_y, r_z)),
+        offset: crate::vec3(t_x, t_y, t_z),
+    })
+}
+
+/// Solves the linear system `a * x = b` using Gaussian elimination with partial pivoting, or
+/// returns [`None`] if `a` is singular.
+fn solve6(mut a: [[Scalar; 6]; 6], mut b: [Scalar; 6]) -> Option<[Scalar; 6]> {
+    let scale = a.iter().flatten().fold(0.0, |m: Scalar, v| m.max(v.abs()));
+    for col in 0..6 {
+        let pivot = (col..6).fold(col, |best, row| {
+            if a[row][col].abs() > a[best][col].abs() {
+                row
+            } else {
+                best
+            }
+        });
+        if a[pivot][col].abs() <= scale * 1e-6 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let (pivot_row, pivot_b) = (a[col], b[col]);
+        for (row, b_row) in a.iter_mut().zip(b.iter_mut()).skip(col + 1) {
+            let factor = row[col] / pivot_row[col];
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row.iter()) {
+                *value -= factor * pivot_value;
+            }
+            *b_row -= factor * pivot_b;
+        }
+    }
+    let mut x = [0.0; 6];
+    for row in (0..6).rev() {
+        let sum: Scalar = ((row + 1)..6).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+#[test]
+fn test_icp() {
+    use crate::vec3;
+    let mut target = Vec::new();
+    let mut normals = Vec::new();
+    for i in 0..31 {
+        for j in 0..31 {
+            let x = i as Scalar / 15.0 - 1.0;
+            let y = j as Scalar / 15.0 - 1.0;
+            let z = 0.4 * (2.0 * x).sin() * (3.0 * y).cos();
+            let d_x = 0.8 * (2.0 * x).cos() * (3.0 * y).cos();
+            let d_y = -1.2 * (2.0 * x).sin() * (3.0 * y).sin();
+            target.push(vec3(x, y, z));
+            normals.push(vec3(-d_x, -d_y, 1.0).normalize());
+        }
+    }
+    let motion = Motion3 {
+        rotation: Rotation3::from_euler(vec3(0.05, -0.03, 0.08)),
+        offset: vec3(0.04, -0.03, 0.02),
+    };
+    let inverse = motion.inverse();
+    let source: Vec<_> = target.iter().map(|p| inverse * *p).collect();
+    for metric in [IcpMetric::PointToPoint, IcpMetric::PointToPlane(&normals)] {
+        let params = IcpParams {
+            metric,
+            max_iterations: 100,
+            ..Default::default()
+        };
+        let res = icp(&source, &target, Motion3::identity(), &params);
+        assert_eq!(res.status, IcpStatus::Converged);
+        assert!(res.rms_error < 1e-3);
+        for p in source.iter().step_by(37) {
+            approx::assert_relative_eq!(res.transform * *p, motion * *p, epsilon = 1e-2);
+        }
+    }
+
+    // Without any correspondences, the result is degenerate
+    let params = IcpParams {
+        max_correspondence_dist: 0.0,
+        ..Default::default()
+    };
+    let far: Vec<_> = source.iter().map(|p| *p + vec3(10.0, 0.0, 0.0)).collect();
+    let res = icp(&far, &target, Motion3::identity(), &params);
+    assert_eq!(res.status, IcpStatus::Degenerate);
+}
+
+#[test]
+#[should_panic]
+fn test_icp_normals_length() {
+    use crate::vec3;
+    let target = [vec3(0.0, 0.0, 0.0), vec3(1.0, 0.0, 0.0)];
+    let normals = [vec3(0.0, 0.0, 1.0)];
+    let params = IcpParams {
+        metric: IcpMetric::PointToPlane(&normals),
+        ..Default::default()
+    };
+    icp(&target, &target, Motion3::identity(), &params);
+}
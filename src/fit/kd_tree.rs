@@ -0,0 +1,137 @@
+use crate::{Scalar, Vector3};
+use alloc::{vec, vec::Vec};
+
+/// A k-d tree over a set of points, supporting nearest-neighbour queries.
+///
+/// The tree is stored implicitly in a permutation of the point indices: the node for the range
+/// `lo..hi` is at index `(lo + hi) / 2`, with its left and right subtrees in the ranges before and
+/// after it.
+pub(super) struct KdTree<'a> {
+    points: &'a [Vector3],
+    indices: Vec<usize>,
+    axes: Vec<u8>,
+}
+
+/// Gets the coordinate of the given point along the given axis.
+#[inline]
+fn coord(point: Vector3, axis: u8) -> Scalar {
+    match axis {
+        0 => point.x,
+        1 => point.y,
+        _ => point.z,
+    }
+}
+
+impl<'a> KdTree<'a> {
+    /// Builds a [`KdTree`] for the given points.
+    pub fn new(points: &'a [Vector3]) -> Self {
+        let mut tree = Self {
+            points,
+            indices: (0..points.len()).collect(),
+            axes: vec![0; points.len()],
+        };
+        tree.build(0, points.len());
+        tree
+    }
+
+    /// Builds the subtree for the given range of `indices`.
+    fn build(&mut self, lo: usize, hi: usize) {
+        if hi - lo <= 1 {
+            return;
+        }
+
+        // Split along the axis with the greatest extent
+        let mut min = [Scalar::INFINITY; 3];
+        let mut max = [Scalar::NEG_INFINITY; 3];
+        for &i in &self.indices[lo..hi] {
+            let p = self.points[i];
+            for (axis, value) in [p.x, p.y, p.z].into_iter().enumerate() {
+                min[axis] = min[axis].min(value);
+                max[axis] = max[axis].max(value);
+            }
+        }
+        let axis = (1..3).fold(0, |best, axis| {
+            if max[axis] - min[axis] > max[best] - min[best] {
+                axis
+            } else {
+                best
+            }
+        }) as u8;
+        let mid = (lo + hi) / 2;
+        let points = self.points;
+        self.indices[lo..hi].select_nth_unstable_by(mid - lo, |a, b| {
+            coord(points[*a], axis).total_cmp(&coord(points[*b], axis))
+        });
+        self.axes[mid] = axis;
+        self.build(lo, mid);
+        self.build(mid + 1, hi);
+    }
+
+    /// Finds the point nearest to `query`, returning its index and squared distance, or returns
+    /// [`None`] if the tree is empty.
+    pub fn nearest(&self, query: Vector3) -> Option<(usize, Scalar)> {
+        let mut best = None;
+        self.search(0, self.indices.len(), query, &mut best);
+        best
+    }
+
+    /// Searches the subtree for the given range of `indices` for a point nearer to `query` than
+    /// `best`.
+    fn search(&self, lo: usize, hi: usize, query: Vector3, best: &mut Option<(usize, Scalar)>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let index = self.indices[mid];
+        let point = self.points[index];
+        let dist_sqr = (point - query).norm_squared();
+        if within(*best, dist_sqr) {
+            *best = Some((index, dist_sqr));
+        }
+        let axis = self.axes[mid];
+        let diff = coord(query, axis) - coord(point, axis);
+        let (near, far) = if diff < 0.0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+        self.search(near.0, near.1, query, best);
+        if within(*best, diff * diff) {
+            self.search(far.0, far.1, query, best);
+        }
+    }
+}
+
+/// Determines whether the given squared distance is less than that of the current best match.
+#[inline]
+fn within(best: Option<(usize, Scalar)>, dist_sqr: Scalar) -> bool {
+    match best {
+        Some((_, best_dist_sqr)) => dist_sqr < best_dist_sqr,
+        None => true,
+    }
+}
+
+#[test]
+fn test_nearest() {
+    use crate::vec3;
+    let mut state = 12345u32;
+    let mut random = move || {
+        state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+        (state >> 8) as Scalar / (1u32 << 24) as Scalar
+    };
+    let points: Vec<_> = (0..500)
+        .map(|_| vec3(random(), random(), random() * 0.2))
+        .collect();
+    let tree = KdTree::new(&points);
+    for _ in 0..100 {
+        let query = vec3(random(), random(), random());
+        let (index, dist_sqr) = tree.nearest(query).unwrap();
+        let expected = points
+            .iter()
+            .map(|p| (*p - query).norm_squared())
+            .fold(Scalar::INFINITY, Scalar::min);
+        assert_eq!(dist_sqr, expected);
+        assert_eq!((points[index] - query).norm_squared(), dist_sqr);
+    }
+    assert_eq!(KdTree::new(&[]).nearest(vec3(0.0, 0.0, 0.0)), None);
+}
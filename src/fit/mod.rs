@@ -0,0 +1,98 @@
+//! This module defines functions for fitting transforms to data, such as finding the transform
+//! which best maps one set of points onto another.
+use crate::{math, Scalar};
+
+mod fit2;
+mod fit3;
+mod icp;
+mod kd_tree;
+
+pub use icp::{icp, IcpMetric, IcpParams, IcpResult, IcpStatus};
+
+/// A transform which can be fit to pairs of corresponding points, minimizing the (weighted) sum
+/// of squared distances between the transformed source points and the target points.
+pub trait FitPoints: Sized {
+    /// The type of point this transform applies to.
+    type Point;
+
+    /// Finds the transform which best maps each point in `source` to the corresponding point in
+    /// `target`, in the least-squares sense.
+    ///
+    /// If `weights` is provided, it gives a non-negative weight for each pair of points. This
+    /// returns [`None`] if any weight is negative or NaN, if the total weight is not positive, or
+    /// if the points don't determine a unique transform where one is required (e.g. fitting an
+    /// affine transform to collinear points).
+    ///
+    /// # Panics
+    /// Panics if `source`, `target` and `weights` don't have the same length.
+    fn fit(
+        source: &[Self::Point],
+        target: &[Self::Point],
+        weights: Option<&[Scalar]>,
+    ) -> Option<FitResult<Self>>;
+}
+
+/// The result of fitting a transform to pairs of corresponding points.
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FitResult<T> {
+    /// The fitted transform.
+    pub transform: T,
+
+    /// The root-mean-square distance between the transformed source points and the target
+    /// points, weighted by the weights of the pairs.
+    pub rms_error: Scalar,
+
+    /// The maximum distance between a transformed source point and its target point, among pairs
+    /// with positive weight.
+    pub max_error: Scalar,
+}
+
+impl<T> FitResult<T> {
+    /// Constructs a [`FitResult`] for the given transform, computing its residuals.
+    fn new<P: Copy>(
+        transform: T,
+        source: &[P],
+        target: &[P],
+        weights: Option<&[Scalar]>,
+        dist_sqr: impl Fn(&T, P, P) -> Scalar,
+    ) -> Self {
+        let mut total_weight = 0.0;
+        let mut total = 0.0;
+        let mut max_error: Scalar = 0.0;
+        for (i, (a, b)) in source.iter().zip(target.iter()).enumerate() {
+            let weight = weight(weights, i);
+            if weight > 0.0 {
+                let dist_sqr = dist_sqr(&transform, *a, *b);
+                total_weight += weight;
+                total += weight * dist_sqr;
+                max_error = max_error.max(math::sqrt(dist_sqr));
+            }
+        }
+        Self {
+            transform,
+            rms_error: math::sqrt(total / total_weight),
+            max_error,
+        }
+    }
+}
+
+/// Gets the weight of the pair of points at the given index.
+#[inline]
+fn weight(weights: Option<&[Scalar]>, index: usize) -> Scalar {
+    weights.map_or(1.0, |weights| weights[index])
+}
+
+/// Determines whether all of the given weights are non-negative. A fit with negative weights is
+/// meaningless, since the weighted covariances are no longer positive semi-definite.
+fn valid_weights(weights: Option<&[Scalar]>) -> bool {
+    weights.is_none_or(|weights| weights.iter().all(|w| *w >= 0.0))
+}
+
+/// Checks that the inputs to [`FitPoints::fit`] have consistent lengths.
+fn check_lengths<P>(source: &[P], target: &[P], weights: Option<&[Scalar]>) {
+    assert_eq!(source.len(), target.len(), "source and target lengths differ");
+    if let Some(weights) = weights {
+        assert_eq!(source.len(), weights.len(), "weights length differs");
+    }
+}
@@ -0,0 +1,257 @@
+use crate::{vec3, Matrix3, Motion3, Projective3, Rotation3, Twist3, Vector3};
+
+/// A linear map from [`Twist3`] to [`Vector3`], such as the Jacobian of a function of a
+/// [`Motion3`] with respect to a perturbation in its tangent space.
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Jacobian3x6 {
+    /// The part of the map which applies to the angular component of the twist.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Matrix3Def"))]
+    pub angular: Matrix3,
+
+    /// The part of the map which applies to the linear component of the twist.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Matrix3Def"))]
+    pub linear: Matrix3,
+}
+
+impl Jacobian3x6 {
+    /// The map which always returns zero.
+    pub const ZERO: Self = Self {
+        angular: ZERO_MATRIX,
+        linear: ZERO_MATRIX,
+    };
+}
+
+impl core::ops::Mul<Twist3> for Jacobian3x6 {
+    type Output = Vector3;
+    fn mul(self, rhs: Twist3) -> Vector3 {
+        self.angular * rhs.angular + self.linear * rhs.linear
+    }
+}
+
+/// A linear map from [`Twist3`] to [`Twist3`], such as the Jacobian of a [`Motion3`]-valued
+/// function of a [`Motion3`], with respect to perturbations in their tangent spaces.
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Jacobian6x6 {
+    /// The map to the angular component of the result.
+    pub angular: Jacobian3x6,
+
+    /// The map to the linear component of the result.
+    pub linear: Jacobian3x6,
+}
+
+impl Jacobian6x6 {
+    /// The identity map.
+    pub const IDENTITY: Self = Self {
+        angular: Jacobian3x6 {
+            angular: Matrix3::identity(),
+            linear: ZERO_MATRIX,
+        },
+        linear: Jacobian3x6 {
+            angular: ZERO_MATRIX,
+            linear: Matrix3::identity(),
+        },
+    };
+}
+
+impl core::ops::Mul<Twist3> for Jacobian6x6 {
+    type Output = Twist3;
+    fn mul(self, rhs: Twist3) -> Twist3 {
+        Twist3 {
+            angular: self.angular * rhs,
+            linear: self.linear * rhs,
+        }
+    }
+}
+
+/// The zero matrix.
+const ZERO_MATRIX: Matrix3 = Matrix3 {
+    x: vec3(0.0, 0.0, 0.0),
+    y: vec3(0.0, 0.0, 0.0),
+    z: vec3(0.0, 0.0, 0.0),
+};
+
+/// Gets the matrix for taking the cross product with the given vector, i.e. `skew(a) * b` is
+/// `a.cross(&b)`.
+fn skew(vec: Vector3) -> Matrix3 {
+    Matrix3 {
+        x: vec3(0.0, vec.z, -vec.y),
+        y: vec3(-vec.z, 0.0, vec.x),
+        z: vec3(vec.y, -vec.x, 0.0),
+    }
+}
+
+impl Rotation3 {
+    /// Gets the Jacobians of `self * point`, with respect to a right-multiplied perturbation of
+    /// this rotation (see [`diffvec::Differentiate`]) and with respect to `point`, respectively.
+    pub fn mul_jacobians(&self, point: Vector3) -> (Matrix3, Matrix3) {
+        let linear = Matrix3::from(*self);
+        (linear * skew(-point), linear)
+    }
+}
+
+impl Motion3 {
+    /// Gets the matrix for the adjoint map of this motion (see [`Motion3::adjoint`]).
+    pub fn adjoint_matrix(&self) -> Jacobian6x6 {
+        let rotation = Matrix3::from(self.rotation);
+        Jacobian6x6 {
+            angular: Jacobian3x6 {
+                angular: rotation,
+                linear: ZERO_MATRIX,
+            },
+            linear: Jacobian3x6 {
+                angular: skew(self.offset) * rotation,
+                linear: rotation,
+            },
+        }
+    }
+
+    /// Gets the Jacobians of `self * point`, with respect to a right-multiplied perturbation of
+    /// this motion (see [`diffvec::Differentiate`]) and with respect to `point`, respectively.
+    pub fn mul_jacobians(&self, point: Vector3) -> (Jacobian3x6, Matrix3) {
+        let (angular, linear) = self.rotation.mul_jacobians(point);
+        (Jacobian3x6 { angular, linear }, linear)
+    }
+
+    /// Gets the Jacobians of `self * rhs`, with respect to right-multiplied perturbations of
+    /// `self` and `rhs` respectively. The result is also perturbed by right-multiplication.
+    pub fn compose_jacobians(&self, rhs: &Motion3) -> (Jacobian6x6, Jacobian6x6) {
+        (rhs.inverse().adjoint_matrix(), Jacobian6x6::IDENTITY)
+    }
+}
+
+impl Projective3 {
+    /// Gets the Jacobian of `self * point` with respect to `point`, i.e. the derivative of the
+    /// projected point, after perspective division, with respect to the original point.
+    pub fn mul_jacobian(&self, point: Vector3) -> Matrix3 {
+        let (r_0, r_1, r_2, r_3) = (self.row(0), self.row(1), self.row(2), self.row(3));
+        let w = r_3.x * point.x + r_3.y * point.y + r_3.z * point.z + r_3.w;
+        let p = *self * point;
+        Matrix3 {
+            x: (vec3(r_0.x, r_1.x, r_2.x) - p * r_3.x) / w,
+            y: (vec3(r_0.y, r_1.y, r_2.y) - p * r_3.y) / w,
+            z: (vec3(r_0.z, r_1.z, r_2.z) - p * r_3.z) / w,
+        }
+    }
+}
+
+#[test]
+fn test_mul_jacobians() {
+    use diffvec::Differentiate;
+    let motion = Motion3 {
+        rotation: Rotation3::from_euler(vec3(0.5, 0.1, -0.7)),
+        offset: vec3(2.0, -1.0, 0.5),
+    };
+    let point = vec3(1.0, -2.0, 0.5);
+    let (j_motion, j_point) = motion.mul_jacobians(point);
+    let h = 1e-2;
+    let zero = vec3(0.0, 0.0, 0.0);
+    let axes = [vec3(h, 0.0, 0.0), vec3(0.0, h, 0.0), vec3(0.0, 0.0, h)];
+    let twists = axes
+        .into_iter()
+        .flat_map(|axis| [Twist3::new(axis, zero), Twist3::new(zero, axis)]);
+    for twist in twists {
+        let (mut a, mut b) = (motion, motion);
+        a.perturb_mut(&twist);
+        b.perturb_mut(&-twist);
+        let numeric = (a * point - b * point) / (2.0 * h);
+        approx::assert_relative_eq!(numeric, j_motion * twist / h, epsilon = 1e-3);
+    }
+    for delta in [vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0)] {
+        let numeric = motion * (point + delta) - motion * point;
+        approx::assert_relative_eq!(numeric, j_point * delta, epsilon = 1e-4);
+    }
+}
+
+#[test]
+fn test_compose_jacobians() {
+    use diffvec::Differentiate;
+    let a = Motion3 {
+        rotation: Rotation3::from_euler(vec3(0.5, 0.1, -0.7)),
+        offset: vec3(2.0, -1.0, 0.5),
+    };
+    let b = Motion3 {
+        rotation: Rotation3::from_euler(vec3(-0.2, 0.9, 0.3)),
+        offset: vec3(-1.0, 0.5, 3.0),
+    };
+    let (j_a, j_b) = a.compose_jacobians(&b);
+    let base = a * b;
+    let h = 1e-2;
+    let zero = vec3(0.0, 0.0, 0.0);
+    let axes = [vec3(h, 0.0, 0.0), vec3(0.0, h, 0.0), vec3(0.0, 0.0, h)];
+    let twists = axes
+        .into_iter()
+        .flat_map(|axis| [Twist3::new(axis, zero), Twist3::new(zero, axis)]);
+    for twist in twists {
+        let mut a_1 = a;
+        a_1.perturb_mut(&twist);
+        let mut b_1 = b;
+        b_1.perturb_mut(&twist);
+        for (actual, j) in [(a_1 * b, j_a), (a * b_1, j_b)] {
+            let expected = base * Motion3::exp(j * twist);
+            let point = vec3(1.0, -2.0, 0.5);
+            approx::assert_relative_eq!(actual * point, expected * point, epsilon = 1e-4);
+        }
+    }
+}
+
+#[test]
+fn test_projective_mul_jacobian() {
+    use crate::conv::Perspective;
+    let proj = Projective3::perspective(1.5, 1.0, 0.5, 50.0);
+    let point = vec3(0.7, -0.4, -3.0);
+    let jacobian = proj.mul_jacobian(point);
+    let h = 1e-2;
+    for delta in [vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0)] {
+        let delta = delta * h;
+        let numeric = (proj * (point + delta) - proj * (point - delta)) / (2.0 * h);
+        approx::assert_relative_eq!(numeric, jacobian * delta / h, epsilon = 1e-3);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use serde_test::{assert_tokens, Token};
+    let matrix = |m: Matrix3| {
+        let mut tokens = vec![Token::Struct { name: "Matrix3", len: 3 }];
+        for (name, col) in [("x", m.x), ("y", m.y), ("z", m.z)] {
+            tokens.extend([
+                Token::Str(name),
+                Token::Struct { name: "Vector3", len: 3 },
+                Token::Str("x"),
+                Token::F32(col.x),
+                Token::Str("y"),
+                Token::F32(col.y),
+                Token::Str("z"),
+                Token::F32(col.z),
+                Token::StructEnd,
+            ]);
+        }
+        tokens.push(Token::StructEnd);
+        tokens
+    };
+    let jacobian = |j: Jacobian3x6| {
+        let mut tokens = vec![Token::Struct { name: "Jacobian3x6", len: 2 }, Token::Str("angular")];
+        tokens.extend(matrix(j.angular));
+        tokens.push(Token::Str("linear"));
+        tokens.extend(matrix(j.linear));
+        tokens.push(Token::StructEnd);
+        tokens
+    };
+    let j = Jacobian3x6 {
+        angular: skew(vec3(1.0, -2.0, 0.5)),
+        linear: Matrix3::identity(),
+    };
+    assert_tokens(&j, &jacobian(j));
+    let j = Jacobian6x6::IDENTITY;
+    let mut tokens = vec![Token::Struct { name: "Jacobian6x6", len: 2 }, Token::Str("angular")];
+    tokens.extend(jacobian(j.angular));
+    tokens.push(Token::Str("linear"));
+    tokens.extend(jacobian(j.linear));
+    tokens.push(Token::StructEnd);
+    assert_tokens(&j, &tokens);
+}
@@ -1,3 +1,9 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or `libm` feature must be enabled");
+
 /// Implements composition of two different types of transformations.
 macro_rules! impl_trans_mul {
     ($sub:ty, $super:ty) => {
@@ -19,12 +25,18 @@ macro_rules! impl_trans_mul {
     };
 }
 
+mod batch;
+mod diff;
+mod jacobian;
+mod math;
 mod rot2;
 mod rot3;
 mod rot2i;
 mod rot3i;
 mod trans2;
 mod trans3;
+mod twist;
+mod proj2;
 mod proj3;
 mod trans2i;
 mod vec2i;
@@ -33,6 +45,13 @@ mod vec3i;
 pub mod shape;
 pub mod time;
 pub mod conv;
+pub mod double;
+pub mod fit;
+pub mod camera;
+pub mod pack;
+pub mod sphere_map;
+#[cfg(feature = "serde")]
+pub mod serde_util;
 
 pub use diffvec::{vec2, vec3, vec4, Matrix2, Matrix3, Matrix4, Vector2, Vector3, Vector4};
 pub use diffvec::{Scalar, PI};
@@ -42,6 +61,9 @@ pub use rot2i::Rotation2i;
 pub use rot3i::Rotation3i;
 pub use trans2::{Affine2, Motion2, Similarity2};
 pub use trans3::{Affine3, Motion3, Similarity3};
+pub use twist::{Twist2, Twist3};
+pub use jacobian::{Jacobian3x6, Jacobian6x6};
+pub use proj2::Projective2;
 pub use proj3::Projective3;
 pub use trans2i::{Motion2i, Ortho2i};
 pub use vec2i::{vec2i, Dir2i, Vector2i};
@@ -51,12 +73,13 @@ pub use vec3i::{vec3i, Dir3i, Vector3i};
 #[repr(u8)]
 #[derive(cantor::Finite, Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serdere", derive(serdere::Deserialize, serdere::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub enum Axis2 {
     #[default]
-    #[cfg_attr(feature = "serdere", serde(rename = "x"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "x"))]
     X = 0,
-    #[cfg_attr(feature = "serdere", serde(rename = "y"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "y"))]
     Y = 1,
 }
 
@@ -77,7 +100,7 @@ impl Axis2 {
     /// The caller must ensure `index < 2`.
     #[inline(always)]
     pub fn new_unchecked(index: usize) -> Self {
-        unsafe { std::mem::transmute::<u8, Axis2>(index as u8) }
+        unsafe { core::mem::transmute::<u8, Axis2>(index as u8) }
     }
 
     /// Gets the index associated with this axis.
@@ -91,14 +114,15 @@ impl Axis2 {
 #[repr(u8)]
 #[derive(cantor::Finite, Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serdere", derive(serdere::Deserialize, serdere::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub enum Axis3 {
     #[default]
-    #[cfg_attr(feature = "serdere", serde(rename = "x"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "x"))]
     X = 0,
-    #[cfg_attr(feature = "serdere", serde(rename = "y"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "y"))]
     Y = 1,
-    #[cfg_attr(feature = "serdere", serde(rename = "z"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "z"))]
     Z = 2,
 }
 
@@ -119,7 +143,7 @@ impl Axis3 {
     /// The caller must ensure `index < 3`.
     #[inline(always)]
     pub fn new_unchecked(index: usize) -> Self {
-        unsafe { std::mem::transmute::<u8, Axis3>(index as u8) }
+        unsafe { core::mem::transmute::<u8, Axis3>(index as u8) }
     }
 
     /// Gets the index associated with this axis.
@@ -133,16 +157,17 @@ impl Axis3 {
 #[repr(u8)]
 #[derive(cantor::Finite, Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serdere", derive(serdere::Deserialize, serdere::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub enum Axis4 {
     #[default]
-    #[cfg_attr(feature = "serdere", serde(rename = "x"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "x"))]
     X = 0,
-    #[cfg_attr(feature = "serdere", serde(rename = "y"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "y"))]
     Y = 1,
-    #[cfg_attr(feature = "serdere", serde(rename = "z"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "z"))]
     Z = 2,
-    #[cfg_attr(feature = "serdere", serde(rename = "w"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "w"))]
     W = 3,
 }
 
@@ -163,7 +188,7 @@ impl Axis4 {
     /// The caller must ensure `index < 4`.
     #[inline(always)]
     pub fn new_unchecked(index: usize) -> Self {
-        unsafe { std::mem::transmute::<u8, Axis4>(index as u8) }
+        unsafe { core::mem::transmute::<u8, Axis4>(index as u8) }
     }
 
     /// Gets the index associated with this axis.
@@ -177,7 +202,7 @@ impl core::ops::Index<Axis2> for Vector2 {
     type Output = Scalar;
     #[inline]
     fn index(&self, axis: Axis2) -> &Scalar {
-        unsafe { std::mem::transmute::<&Vector2, &[Scalar; 2]>(self).get_unchecked(axis.index()) }
+        unsafe { core::mem::transmute::<&Vector2, &[Scalar; 2]>(self).get_unchecked(axis.index()) }
     }
 }
 
@@ -185,7 +210,7 @@ impl core::ops::IndexMut<Axis2> for Vector2 {
     #[inline]
     fn index_mut(&mut self, axis: Axis2) -> &mut Scalar {
         unsafe {
-            std::mem::transmute::<&mut Vector2, &mut [Scalar; 2]>(self)
+            core::mem::transmute::<&mut Vector2, &mut [Scalar; 2]>(self)
                 .get_unchecked_mut(axis.index())
         }
     }
@@ -195,7 +220,7 @@ impl core::ops::Index<Axis3> for Vector3 {
     type Output = Scalar;
     #[inline]
     fn index(&self, axis: Axis3) -> &Scalar {
-        unsafe { std::mem::transmute::<&Vector3, &[Scalar; 3]>(self).get_unchecked(axis.index()) }
+        unsafe { core::mem::transmute::<&Vector3, &[Scalar; 3]>(self).get_unchecked(axis.index()) }
     }
 }
 
@@ -203,7 +228,7 @@ impl core::ops::IndexMut<Axis3> for Vector3 {
     #[inline]
     fn index_mut(&mut self, axis: Axis3) -> &mut Scalar {
         unsafe {
-            std::mem::transmute::<&mut Vector3, &mut [Scalar; 3]>(self)
+            core::mem::transmute::<&mut Vector3, &mut [Scalar; 3]>(self)
                 .get_unchecked_mut(axis.index())
         }
     }
@@ -213,7 +238,7 @@ impl core::ops::Index<Axis4> for Vector4 {
     type Output = Scalar;
     #[inline]
     fn index(&self, axis: Axis4) -> &Scalar {
-        unsafe { std::mem::transmute::<&Vector4, &[Scalar; 4]>(self).get_unchecked(axis.index()) }
+        unsafe { core::mem::transmute::<&Vector4, &[Scalar; 4]>(self).get_unchecked(axis.index()) }
     }
 }
 
@@ -221,7 +246,7 @@ impl core::ops::IndexMut<Axis4> for Vector4 {
     #[inline]
     fn index_mut(&mut self, axis: Axis4) -> &mut Scalar {
         unsafe {
-            std::mem::transmute::<&mut Vector4, &mut [Scalar; 4]>(self)
+            core::mem::transmute::<&mut Vector4, &mut [Scalar; 4]>(self)
                 .get_unchecked_mut(axis.index())
         }
     }
@@ -249,11 +274,12 @@ fn test_vector_index() {
 #[repr(u8)]
 #[derive(cantor::Finite, Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serdere", derive(serdere::Deserialize, serdere::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub enum Dir1 {
     #[default]
-    #[cfg_attr(feature = "serdere", serde(rename = "p"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "p"))]
     P = 0,
-    #[cfg_attr(feature = "serdere", serde(rename = "n"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "n"))]
     N = 1,
 }
@@ -0,0 +1,194 @@
+//! Floating-point functions which aren't available in `core`. These use the standard library
+//! when the `std` feature is enabled, and `libm` otherwise.
+
+/// A floating-point type supported by the functions in this module.
+pub(crate) trait Real: Copy {
+    fn sqrt(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn tan(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+}
+
+/// Implements [`Real`] for a primitive floating-point type, given the names of the corresponding
+/// `libm` functions.
+macro_rules! impl_real {
+    (
+        $t:ty, $sqrt:ident, $sin:ident, $cos:ident, $tan:ident, $acos:ident, $atan:ident,
+        $atan2:ident, $powf:ident, $floor:ident, $ceil:ident, $round:ident
+    ) => {
+        #[cfg(feature = "std")]
+        impl Real for $t {
+            #[inline]
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                <$t>::sin_cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                <$t>::tan(self)
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                <$t>::acos(self)
+            }
+
+            #[inline]
+            fn atan(self) -> Self {
+                <$t>::atan(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                <$t>::atan2(self, other)
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                <$t>::powf(self, n)
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                <$t>::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                <$t>::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                <$t>::round(self)
+            }
+        }
+
+        #[cfg(not(feature = "std"))]
+        impl Real for $t {
+            #[inline]
+            fn sqrt(self) -> Self {
+                libm::$sqrt(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                (libm::$sin(self), libm::$cos(self))
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                libm::$tan(self)
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                libm::$acos(self)
+            }
+
+            #[inline]
+            fn atan(self) -> Self {
+                libm::$atan(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                libm::$atan2(self, other)
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                libm::$powf(self, n)
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                libm::$floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                libm::$ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                libm::$round(self)
+            }
+        }
+    };
+}
+
+impl_real!(f32, sqrtf, sinf, cosf, tanf, acosf, atanf, atan2f, powf, floorf, ceilf, roundf);
+impl_real!(f64, sqrt, sin, cos, tan, acos, atan, atan2, pow, floor, ceil, round);
+
+/// Computes the square root of `x`.
+#[inline]
+pub(crate) fn sqrt<T: Real>(x: T) -> T {
+    x.sqrt()
+}
+
+/// Computes the sine and cosine of `x`, in radians.
+#[inline]
+pub(crate) fn sin_cos<T: Real>(x: T) -> (T, T) {
+    x.sin_cos()
+}
+
+/// Computes the tangent of `x`, in radians.
+#[inline]
+pub(crate) fn tan<T: Real>(x: T) -> T {
+    x.tan()
+}
+
+/// Computes the arccosine of `x`, in radians.
+#[inline]
+pub(crate) fn acos<T: Real>(x: T) -> T {
+    x.acos()
+}
+
+/// Computes the arctangent of `x`, in radians.
+#[inline]
+pub(crate) fn atan<T: Real>(x: T) -> T {
+    x.atan()
+}
+
+/// Computes the four-quadrant arctangent of `y` and `x`, in radians.
+#[inline]
+pub(crate) fn atan2<T: Real>(y: T, x: T) -> T {
+    y.atan2(x)
+}
+
+/// Raises `x` to the power `n`.
+#[inline]
+pub(crate) fn powf<T: Real>(x: T, n: T) -> T {
+    x.powf(n)
+}
+
+/// Gets the largest integer less than or equal to `x`.
+#[inline]
+pub(crate) fn floor<T: Real>(x: T) -> T {
+    x.floor()
+}
+
+/// Gets the smallest integer greater than or equal to `x`.
+#[inline]
+pub(crate) fn ceil<T: Real>(x: T) -> T {
+    x.ceil()
+}
+
+/// Gets the integer nearest to `x`, rounding half-way cases away from zero.
+#[inline]
+pub(crate) fn round<T: Real>(x: T) -> T {
+    x.round()
+}
@@ -0,0 +1,30 @@
+//! This module defines compact, quantized encodings of geometric objects, for use in GPU buffers,
+//! file formats and network protocols.
+use crate::{math, Scalar};
+
+mod normal;
+mod rot;
+
+pub use normal::{OctNormal16x2, OctNormal32, QTangent};
+pub use rot::{PackedRotation2, PackedRotation3};
+
+/// Quantizes a value in \[-1, 1\] to an unsigned integer with the given number of bits (at least
+/// 2). Zero is represented exactly, at the cost of leaving the largest integer value unused.
+#[inline]
+fn quantize_snorm(value: Scalar, bits: u32) -> u32 {
+    let half = (1i64 << (bits - 1)) - 1;
+    (math::round(value.clamp(-1.0, 1.0) * half as Scalar) as i64 + half) as u32
+}
+
+/// The inverse of [`quantize_snorm`].
+#[inline]
+fn dequantize_snorm(value: u32, bits: u32) -> Scalar {
+    let half = (1i64 << (bits - 1)) - 1;
+    ((value as i64 - half) as Scalar / half as Scalar).clamp(-1.0, 1.0)
+}
+
+/// Gets a mask for the lowest `bits` bits of an integer.
+#[inline]
+fn mask(bits: u32) -> u32 {
+    ((1u64 << bits) - 1) as u32
+}
@@ -0,0 +1,228 @@
+use super::{dequantize_snorm, mask, quantize_snorm};
+use crate::sphere_map::{dir_to_octahedral, octahedral_to_dir};
+use crate::{math, vec2, vec4, Matrix3, Rotation3, Scalar, Vector3, Vector4};
+
+/// A unit vector, encoded using quantized octahedral coordinates (see [`dir_to_octahedral`]) packed
+/// into a single [`u32`].
+///
+/// The number of bits per coordinate, which must be between 2 and 16, is chosen when encoding and
+/// must be the same when decoding. The U coordinate is stored in the lowest bits.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct OctNormal32(pub u32);
+
+impl OctNormal32 {
+    /// Encodes a unit vector using the given number of bits per coordinate.
+    ///
+    /// # Panics
+    /// Panics if `bits` is not between 2 and 16.
+    pub fn encode(dir: Vector3, bits: u32) -> Self {
+        assert!((2..=16).contains(&bits), "bits per coordinate must be between 2 and 16");
+        let uv = dir_to_octahedral(dir);
+        Self(quantize_snorm(uv.x, bits) | (quantize_snorm(uv.y, bits) << bits))
+    }
+
+    /// Decodes this unit vector, given the number of bits per coordinate it was encoded with.
+    ///
+    /// # Panics
+    /// Panics if `bits` is not between 2 and 16.
+    pub fn decode(self, bits: u32) -> Vector3 {
+        assert!((2..=16).contains(&bits), "bits per coordinate must be between 2 and 16");
+        let u = dequantize_snorm(self.0 & mask(bits), bits);
+        let v = dequantize_snorm((self.0 >> bits) & mask(bits), bits);
+        octahedral_to_dir(vec2(u, v))
+    }
+}
+
+/// A unit vector, encoded using quantized octahedral coordinates (see [`dir_to_octahedral`]) stored
+/// in a pair of [`u16`]s. This is suitable for use as a two-component vertex attribute.
+///
+/// The number of bits per coordinate, which must be between 2 and 16, is chosen when encoding and
+/// must be the same when decoding. Each coordinate is stored in the lowest bits of its [`u16`].
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct OctNormal16x2(pub [u16; 2]);
+
+impl OctNormal16x2 {
+    /// Encodes a unit vector using the given number of bits per coordinate.
+    ///
+    /// # Panics
+    /// Panics if `bits` is not between 2 and 16.
+    pub fn encode(dir: Vector3, bits: u32) -> Self {
+        assert!((2..=16).contains(&bits), "bits per coordinate must be between 2 and 16");
+        let uv = dir_to_octahedral(dir);
+        Self([
+            quantize_snorm(uv.x, bits) as u16,
+            quantize_snorm(uv.y, bits) as u16,
+        ])
+    }
+
+    /// Decodes this unit vector, given the number of bits per coordinate it was encoded with.
+    ///
+    /// # Panics
+    /// Panics if `bits` is not between 2 and 16.
+    pub fn decode(self, bits: u32) -> Vector3 {
+        assert!((2..=16).contains(&bits), "bits per coordinate must be between 2 and 16");
+        let u = dequantize_snorm(self.0[0] as u32, bits);
+        let v = dequantize_snorm(self.0[1] as u32, bits);
+        octahedral_to_dir(vec2(u, v))
+    }
+}
+
+/// A tangent frame (tangent, bitangent and normal), encoded as a rotation along with a flag
+/// indicating whether the bitangent is reflected.
+///
+/// The rotation maps the X, Y and Z axes to the tangent, bitangent and normal respectively, with
+/// the bitangent negated if the frame is reflected.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QTangent {
+    /// The rotation of the frame.
+    pub rotation: Rotation3,
+
+    /// Whether the frame is left-handed, i.e. the bitangent is the negation of the cross product
+    /// of the normal and the tangent.
+    pub reflected: bool,
+}
+
+/// The minimum magnitude of the W component of a quaternion packed by [`QTangent::to_vec4`]. This
+/// ensures that its sign is preserved when stored as a 16-bit normalized integer.
+const QTANGENT_BIAS: Scalar = 1.0 / 32767.0;
+
+impl QTangent {
+    /// Encodes the given tangent frame, which is assumed to be orthonormal.
+    pub fn from_frame(tangent: Vector3, bitangent: Vector3, normal: Vector3) -> Self {
+        let reflected = tangent.cross(&bitangent).dot(&normal) < 0.0;
+        let bitangent = if reflected { -bitangent } else { bitangent };
+        Self {
+            rotation: Rotation3::from_matrix(Matrix3 {
+                x: tangent,
+                y: bitangent,
+                z: normal,
+            }),
+            reflected,
+        }
+    }
+
+    /// Decodes this tangent frame, returning the tangent, bitangent and normal.
+    pub fn to_frame(&self) -> (Vector3, Vector3, Vector3) {
+        let matrix = Matrix3::from(self.rotation);
+        let bitangent = if self.reflected { -matrix.y } else { matrix.y };
+        (matrix.x, bitangent, matrix.z)
+    }
+
+    /// Packs this tangent frame into a single quaternion, in `(x, y, z, w)` order, where the sign
+    /// of the W component indicates whether the frame is reflected.
+    ///
+    /// The magnitude of W is kept above a small bias, so that its sign survives quantization to
+    /// 16-bit (or larger) normalized integers.
+    pub fn to_vec4(&self) -> Vector4 {
+        let mut w = self.rotation.w();
+        let mut x_y_z = self.rotation.x_y_z();
+        if w < 0.0 {
+            w = -w;
+            x_y_z = -x_y_z;
+        }
+        if w < QTANGENT_BIAS {
+            let scale = math::sqrt(1.0 - QTANGENT_BIAS * QTANGENT_BIAS) / x_y_z.norm();
+            w = QTANGENT_BIAS;
+            x_y_z = x_y_z * scale;
+        }
+        let q = vec4(x_y_z.x, x_y_z.y, x_y_z.z, w);
+        if self.reflected {
+            -q
+        } else {
+            q
+        }
+    }
+
+    /// Unpacks a tangent frame produced by [`QTangent::to_vec4`]. The quaternion does not need to
+    /// be normalized.
+    pub fn from_vec4(q: Vector4) -> Self {
+        let reflected = q.w < 0.0;
+        let norm = math::sqrt(q.x * q.x + q.y * q.y + q.z * q.z + q.w * q.w);
+        Self {
+            rotation: Rotation3::new_unchecked(q.w / norm, q.x / norm, q.y / norm, q.z / norm),
+            reflected,
+        }
+    }
+}
+
+#[cfg(test)]
+fn angle_between(a: Vector3, b: Vector3) -> Scalar {
+    a.cross(&b).norm().atan2(a.dot(&b))
+}
+
+#[test]
+fn test_oct_normal() {
+    use crate::vec3;
+    let dirs = [
+        vec3(0.0, 0.0, 1.0),
+        vec3(0.0, 0.0, -1.0),
+        vec3(1.0, 0.0, 0.0),
+        vec3(0.3, -0.5, 0.8),
+        vec3(-0.9, 0.2, -0.1),
+        vec3(-0.2, -0.3, -0.9),
+    ];
+    for bits in [8, 10, 12, 16] {
+        let max_error = 8.0 / (1u32 << bits) as Scalar;
+        for dir in dirs {
+            let dir = dir.normalize();
+            let a = OctNormal32::encode(dir, bits);
+            assert!(angle_between(a.decode(bits), dir) <= max_error);
+            assert!(bits == 16 || a.0 >> (2 * bits) == 0);
+            let b = OctNormal16x2::encode(dir, bits);
+            assert!(angle_between(b.decode(bits), dir) <= max_error);
+        }
+    }
+
+    // Axis-aligned vectors should be represented exactly
+    let dir = OctNormal32::encode(vec3(0.0, 0.0, 1.0), 8).decode(8);
+    assert_eq!(dir, vec3(0.0, 0.0, 1.0));
+}
+
+#[test]
+#[should_panic]
+fn test_oct_normal_too_many_bits() {
+    OctNormal32::encode(crate::vec3(0.0, 0.0, 1.0), 17);
+}
+
+#[test]
+#[should_panic]
+fn test_oct_normal_too_few_bits() {
+    OctNormal16x2([0, 0]).decode(1);
+}
+
+#[test]
+fn test_qtangent() {
+    use crate::vec3;
+    let rotation = Rotation3::from_euler(vec3(0.3, -1.2, 2.0));
+    let matrix = Matrix3::from(rotation);
+    for reflected in [false, true] {
+        let bitangent = if reflected { -matrix.y } else { matrix.y };
+        let qtangent = QTangent::from_frame(matrix.x, bitangent, matrix.z);
+        assert_eq!(qtangent.reflected, reflected);
+        let unpacked = QTangent::from_vec4(qtangent.to_vec4());
+        assert_eq!(unpacked.reflected, reflected);
+        let (t, b, n) = unpacked.to_frame();
+        approx::assert_relative_eq!(t, matrix.x, epsilon = 1e-5);
+        approx::assert_relative_eq!(b, bitangent, epsilon = 1e-5);
+        approx::assert_relative_eq!(n, matrix.z, epsilon = 1e-5);
+    }
+
+    // The reflection should be preserved even when the W component is zero
+    let rotation = Rotation3::new_unchecked(0.0, 1.0, 0.0, 0.0);
+    let qtangent = QTangent {
+        rotation,
+        reflected: true,
+    };
+    let q = qtangent.to_vec4();
+    assert!(q.w < 0.0);
+    assert!(QTangent::from_vec4(q).reflected);
+}
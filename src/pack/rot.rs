@@ -0,0 +1,179 @@
+use super::{dequantize_snorm, mask, quantize_snorm};
+use crate::{math, Rotation2, Rotation3, Scalar};
+
+/// A [`Rotation3`], encoded using the "smallest three" scheme and packed into a single [`u32`].
+///
+/// The quaternion component with the largest magnitude is dropped (it can be recovered from the
+/// others, up to sign, since quaternions `q` and `-q` represent the same rotation), and the
+/// remaining three components are quantized. The number of bits per component, which must be
+/// between 2 and 10, is chosen when encoding and must be the same when decoding. The total size is
+/// `3 * component_bits + 2` bits, e.g. 29 bits for 9-bit components or 32 bits for 10-bit
+/// components. The index of the dropped component is stored in the highest of these bits.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct PackedRotation3(pub u32);
+
+impl PackedRotation3 {
+    /// Encodes a rotation using the given number of bits per component.
+    ///
+    /// # Panics
+    /// Panics if `component_bits` is not between 2 and 10.
+    pub fn encode(rot: Rotation3, component_bits: u32) -> Self {
+        assert!((2..=10).contains(&component_bits), "bits per component must be between 2 and 10");
+        let x_y_z = rot.x_y_z();
+        let q = [rot.w(), x_y_z.x, x_y_z.y, x_y_z.z];
+        let mut largest = 0;
+        for (i, c) in q.iter().enumerate().skip(1) {
+            if c.abs() > q[largest].abs() {
+                largest = i;
+            }
+        }
+        let sign = if q[largest] < 0.0 { -1.0 } else { 1.0 };
+        let mut res = (largest as u32) << (3 * component_bits);
+        let mut shift = 0;
+        for (i, c) in q.into_iter().enumerate() {
+            if i != largest {
+                let c = c * sign * core::f32::consts::SQRT_2;
+                res |= quantize_snorm(c, component_bits) << shift;
+                shift += component_bits;
+            }
+        }
+        Self(res)
+    }
+
+    /// Decodes this rotation, given the number of bits per component it was encoded with.
+    ///
+    /// # Panics
+    /// Panics if `component_bits` is not between 2 and 10.
+    pub fn decode(self, component_bits: u32) -> Rotation3 {
+        assert!((2..=10).contains(&component_bits), "bits per component must be between 2 and 10");
+        let largest = ((self.0 >> (3 * component_bits)) & 0b11) as usize;
+        let mut q = [0.0; 4];
+        let mut shift = 0;
+        let mut norm_sqr = 0.0;
+        for (i, c) in q.iter_mut().enumerate() {
+            if i != largest {
+                let value = (self.0 >> shift) & mask(component_bits);
+                *c = dequantize_snorm(value, component_bits) / core::f32::consts::SQRT_2;
+                norm_sqr += *c * *c;
+                shift += component_bits;
+            }
+        }
+        if norm_sqr <= 1.0 {
+            q[largest] = math::sqrt(1.0 - norm_sqr);
+            Rotation3::new_unchecked(q[0], q[1], q[2], q[3])
+        } else {
+            let norm = math::sqrt(norm_sqr);
+            Rotation3::new_unchecked(q[0] / norm, q[1] / norm, q[2] / norm, q[3] / norm)
+        }
+    }
+}
+
+/// A [`Rotation2`], quantized to an integer with a configurable number of bits.
+///
+/// This stores the tangent of a quarter of the rotation angle, which is obtained from the
+/// tangent-half-angle representation of [`Rotation2`] without evaluating any trigonometric
+/// functions. It takes values in \[-1, 1\], covering all rotations, and is spread close to
+/// uniformly over the angle (the angular resolution varies by at most a factor of two). The
+/// number of bits, which must be between 2 and 32, is chosen when encoding and must be the same
+/// when decoding.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct PackedRotation2(pub u32);
+
+impl PackedRotation2 {
+    /// Encodes a rotation using the given number of bits.
+    ///
+    /// # Panics
+    /// Panics if `bits` is not between 2 and 32.
+    pub fn encode(rot: Rotation2, bits: u32) -> Self {
+        assert!((2..=32).contains(&bits), "bits must be between 2 and 32");
+        let t = rot.tan_half_angle();
+        let tan_quarter_angle = if t.abs() <= 1.0 {
+            t / (1.0 + math::sqrt(1.0 + t * t))
+        } else {
+            // Reformulate in terms of the reciprocal to handle infinite values
+            let u = 1.0 / t;
+            1.0 / (u + u.signum() * math::sqrt(1.0 + u * u))
+        };
+        Self(quantize_snorm(tan_quarter_angle, bits))
+    }
+
+    /// Decodes this rotation, given the number of bits it was encoded with.
+    ///
+    /// # Panics
+    /// Panics if `bits` is not between 2 and 32.
+    pub fn decode(self, bits: u32) -> Rotation2 {
+        assert!((2..=32).contains(&bits), "bits must be between 2 and 32");
+        let s = dequantize_snorm(self.0, bits);
+        Rotation2::from_tan_half_angle(2.0 * s / (1.0 - s * s))
+    }
+}
+
+#[test]
+fn test_packed_rotation3() {
+    use crate::vec3;
+    let rotations = [
+        vec3(0.3, -1.2, 2.0),
+        vec3(3.1, 0.0, 0.0),
+        vec3(-0.5, 0.5, 0.1),
+        vec3(0.0, 2.5, -2.0),
+        vec3(1e-3, 0.0, 0.0),
+    ]
+    .map(Rotation3::from_euler);
+    for component_bits in [9, 10] {
+        let max_error = 16.0 / (1u32 << component_bits) as Scalar;
+        for rot in rotations.into_iter().chain([Rotation3::IDENTITY]) {
+            let packed = PackedRotation3::encode(rot, component_bits);
+            assert!(component_bits == 10 || packed.0 >> (3 * component_bits + 2) == 0);
+            let decoded = packed.decode(component_bits);
+
+            // Check the angle of the rotation between the original and decoded rotations
+            let diff = rot.inverse() * decoded;
+            let error = 2.0 * diff.x_y_z().norm().atan2(diff.w().abs());
+            assert!(error <= max_error);
+
+            // Re-encoding the decoded rotation should give the same result
+            assert_eq!(PackedRotation3::encode(decoded, component_bits), packed);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_packed_rotation3_too_many_bits() {
+    PackedRotation3::encode(Rotation3::IDENTITY, 11);
+}
+
+#[test]
+fn test_packed_rotation2() {
+    for bits in [8, 12, 16] {
+        let max_error = 8.0 / (1u32 << bits) as Scalar;
+        for angle in [0.0, 0.3, -1.0, 2.5, -3.0, crate::PI] {
+            let rot = Rotation2::from_angle(angle);
+            let packed = PackedRotation2::encode(rot, bits);
+            let decoded = packed.decode(bits);
+            let (sin, cos) = (rot.inverse() * decoded).angle_sin_cos();
+            assert!(sin.atan2(cos).abs() <= max_error);
+            assert_eq!(PackedRotation2::encode(decoded, bits), packed);
+        }
+    }
+    assert_eq!(
+        PackedRotation2::encode(Rotation2::IDENTITY, 8).decode(8),
+        Rotation2::IDENTITY
+    );
+    let flip = PackedRotation2::encode(Rotation2::FLIP, 8).decode(8);
+    assert_eq!(flip.angle_sin_cos().1, -1.0);
+}
+
+#[test]
+#[should_panic]
+fn test_packed_rotation2_too_few_bits() {
+    PackedRotation2(0).decode(1);
+}
@@ -0,0 +1,207 @@
+use crate::{
+    vec2, vec3, Affine2, Matrix3, Motion2, Rotation2, Scalar, Similarity2, Vector2, Vector3,
+};
+
+/// A projective transform (homography) in two-dimensional space.
+#[repr(transparent)]
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Projective2(
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Matrix3Def"))] Matrix3,
+);
+
+impl Projective2 {
+    /// The identity projective transform.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self(Matrix3::identity())
+    }
+
+    /// Constructs a projective transform from its 3x3 matrix representation.
+    #[inline]
+    pub const fn new(matrix: Matrix3) -> Self {
+        Self(matrix)
+    }
+
+    /// Gets the 3x3 matrix representation of this projective transform.
+    #[inline]
+    pub const fn as_matrix(&self) -> &Matrix3 {
+        &self.0
+    }
+
+    /// Gets the inverse of this projective transform.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Self(self.0.inverse())
+    }
+
+    /// Constructs the projective transform which maps each of the given `source` points to the
+    /// corresponding `target` point, or returns [`None`] if any three of the `source` or `target`
+    /// points are collinear.
+    ///
+    /// This is useful for rectifying a quadrilateral region of an image, e.g. by mapping the
+    /// corners of a unit square to the corners of the region.
+    pub fn from_points(source: [Vector2; 4], target: [Vector2; 4]) -> Option<Self> {
+        let source = from_basis(source)?;
+        let target = from_basis(target)?;
+        Some(Self(target * source.inverse()))
+    }
+}
+
+/// Gets the matrix which maps the homogeneous points `(1, 0, 0)`, `(0, 1, 0)`, `(0, 0, 1)` and
+/// `(1, 1, 1)` to the given points, or returns [`None`] if any three of them are collinear.
+fn from_basis(points: [Vector2; 4]) -> Option<Matrix3> {
+    let [a, b, c, d] = points.map(|p| vec3(p.x, p.y, 1.0));
+
+    // Scale the columns so that their sum is `d`. By Cramer's rule, the scale factors are ratios
+    // of triple products, each of which is proportional to the area of a triangle of points
+    let triple = |x: Vector3, y: Vector3, z: Vector3| x.dot(&y.cross(&z));
+    let areas = [
+        triple(a, b, c),
+        triple(d, b, c),
+        triple(a, d, c),
+        triple(a, b, d),
+    ];
+    let extent = points
+        .iter()
+        .fold(0.0, |m: Scalar, p| m.max(p.x.abs()).max(p.y.abs()));
+    let min_area = 1e-6 * extent * extent;
+    if areas.iter().any(|area| area.abs() <= min_area) {
+        return None;
+    }
+    let lambda = vec3(areas[1], areas[2], areas[3]) / areas[0];
+    Some(Matrix3 {
+        x: a * lambda.x,
+        y: b * lambda.y,
+        z: c * lambda.z,
+    })
+}
+
+impl From<Rotation2> for Projective2 {
+    #[inline]
+    fn from(rotation: Rotation2) -> Self {
+        Affine2::from(rotation).into()
+    }
+}
+
+impl From<Motion2> for Projective2 {
+    #[inline]
+    fn from(motion: Motion2) -> Self {
+        Affine2::from(motion).into()
+    }
+}
+
+impl From<Similarity2> for Projective2 {
+    #[inline]
+    fn from(similarity: Similarity2) -> Self {
+        Affine2::from(similarity).into()
+    }
+}
+
+impl From<Affine2> for Projective2 {
+    #[inline]
+    fn from(affine: Affine2) -> Self {
+        Self(Matrix3 {
+            x: vec3(affine.linear.x.x, affine.linear.x.y, 0.0),
+            y: vec3(affine.linear.y.x, affine.linear.y.y, 0.0),
+            z: vec3(affine.offset.x, affine.offset.y, 1.0),
+        })
+    }
+}
+
+impl_trans_mul!(Rotation2, Projective2);
+impl_trans_mul!(Motion2, Projective2);
+impl_trans_mul!(Similarity2, Projective2);
+impl_trans_mul!(Affine2, Projective2);
+
+impl core::ops::Mul<Projective2> for Projective2 {
+    type Output = Projective2;
+    #[inline]
+    fn mul(self, rhs: Projective2) -> Projective2 {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl core::ops::Mul<Vector2> for Projective2 {
+    type Output = Vector2;
+    #[inline]
+    fn mul(self, rhs: Vector2) -> Vector2 {
+        let r = self.0 * vec3(rhs.x, rhs.y, 1.0);
+        vec2(r.x, r.y) / r.z
+    }
+}
+
+impl core::ops::Mul<Vector3> for Projective2 {
+    type Output = Vector3;
+    #[inline]
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        self.0 * rhs
+    }
+}
+
+#[test]
+fn test_from_points() {
+    let source = [
+        vec2(0.0, 0.0),
+        vec2(1.0, 0.0),
+        vec2(1.0, 1.0),
+        vec2(0.0, 1.0),
+    ];
+    let target = [
+        vec2(10.0, 20.0),
+        vec2(50.0, 15.0),
+        vec2(45.0, 60.0),
+        vec2(12.0, 40.0),
+    ];
+    let proj = Projective2::from_points(source, target).unwrap();
+    for (a, b) in source.iter().zip(target.iter()) {
+        approx::assert_relative_eq!(proj * *a, *b, epsilon = 1e-3);
+        approx::assert_relative_eq!(proj.inverse() * *b, *a, epsilon = 1e-4);
+    }
+
+    // An affine map should be recovered exactly
+    let affine = Affine2 {
+        linear: crate::Matrix2 {
+            x: vec2(1.0, 0.5),
+            y: vec2(-0.2, 2.0),
+        },
+        offset: vec2(1.0, -3.0),
+    };
+    let proj = Projective2::from_points(source, source.map(|p| affine * p)).unwrap();
+    let x = vec2(0.3, 2.5);
+    approx::assert_relative_eq!(proj * x, affine * x, epsilon = 1e-4);
+
+    // Collinear points don't determine a homography
+    let mut degenerate = target;
+    degenerate[2] = vec2(30.0, 17.5);
+    assert!(Projective2::from_points(source, degenerate).is_none());
+
+    // The collinearity threshold is relative to the scale of the points, so small quadrilaterals
+    // are accepted
+    let small = source.map(|p| p * 1e-3);
+    let proj = Projective2::from_points(small, target).unwrap();
+    for (a, b) in small.iter().zip(target.iter()) {
+        approx::assert_relative_eq!(proj * *a, *b, epsilon = 1e-2);
+    }
+    let mut degenerate = small;
+    degenerate[2] = vec2(2e-3, 0.0);
+    assert!(Projective2::from_points(degenerate, target).is_none());
+}
+
+#[test]
+fn test_compose() {
+    let a = Projective2::new(Matrix3 {
+        x: vec3(1.5, 0.2, 0.1),
+        y: vec3(0.0, 2.0, -0.05),
+        z: vec3(1.0, -1.0, 1.0),
+    });
+    let b = Motion2 {
+        rotation: Rotation2::from_angle(0.7),
+        offset: vec2(0.5, 0.25),
+    };
+    let x = vec2(0.3, -0.4);
+    approx::assert_relative_eq!((a * b) * x, a * (b * x), epsilon = 1e-4);
+    approx::assert_relative_eq!((b * a) * x, b * (a * x), epsilon = 1e-4);
+    approx::assert_relative_eq!(a.inverse() * (a * x), x, epsilon = 1e-4);
+}
@@ -1,10 +1,13 @@
-use crate::{vec3, vec4, Affine3, Matrix4, Motion3, Rotation3, Similarity3, Vector3};
+use crate::{vec3, vec4, Affine3, Matrix4, Motion3, Rotation3, Similarity3, Vector3, Vector4};
 
 /// A projective transform in three-dimensional space.
 #[repr(transparent)]
 #[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
-pub struct Projective3(Matrix4);
+pub struct Projective3(
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Matrix4Def"))] Matrix4,
+);
 
 impl Projective3 {
     /// The identity projective transform.
@@ -24,6 +27,30 @@ impl Projective3 {
     pub const fn as_matrix(&self) -> &Matrix4 {
         &self.0
     }
+
+    /// Gets the row of the 4x4 matrix representation of this projective transform at the given
+    /// index.
+    pub(crate) fn row(&self, index: usize) -> Vector4 {
+        let m = &self.0;
+        let get = |col: &Vector4| [col.x, col.y, col.z, col.w][index];
+        vec4(get(&m.x), get(&m.y), get(&m.z), get(&m.w))
+    }
+
+    /// Gets the inverse of this projective transform.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Self(self.0.inverse())
+    }
+
+    /// Maps a point in the target space of this transform (e.g. normalized device coordinates)
+    /// back to the source space (e.g. view or world space).
+    ///
+    /// This is equivalent to applying the [`Projective3::inverse`] of this transform. When
+    /// unprojecting many points, it is faster to compute the inverse once and reuse it.
+    #[inline]
+    pub fn unproject(&self, ndc: Vector3) -> Vector3 {
+        self.inverse() * ndc
+    }
 }
 
 impl From<Rotation3> for Projective3 {
@@ -79,4 +106,37 @@ impl core::ops::Mul<Vector3> for Projective3 {
         let r = self.0 * vec4(rhs.x, rhs.y, rhs.z, 1.0);
         vec3(r.x, r.y, r.z) / r.w
     }
-}
\ No newline at end of file
+}
+
+impl core::ops::Mul<Vector4> for Projective3 {
+    type Output = Vector4;
+    #[inline]
+    fn mul(self, rhs: Vector4) -> Vector4 {
+        self.0 * rhs
+    }
+}
+
+#[test]
+fn test_inverse() {
+    let proj = Projective3::new(Matrix4 {
+        x: vec4(1.5, 0.0, 0.0, 0.0),
+        y: vec4(0.0, 2.0, 0.0, 0.0),
+        z: vec4(0.25, 0.0, 0.5, -1.0),
+        w: vec4(1.0, 0.0, 2.0, 0.0),
+    }) * Motion3 {
+        rotation: Rotation3::from_euler(vec3(0.5, 1.0, 0.2)),
+        offset: vec3(1.0, 2.0, 3.0),
+    };
+    let x = vec3(0.3, -0.2, 0.7);
+    approx::assert_relative_eq!(proj.unproject(proj * x), x, epsilon = 1e-4);
+    approx::assert_relative_eq!(proj * proj.unproject(x), x, epsilon = 1e-4);
+}
+
+#[test]
+fn test_mul_vector4() {
+    let proj = Projective3::from(Affine3::scale(2.0, 3.0, 4.0))
+        * Motion3::translate(vec3(1.0, 0.0, 0.0));
+    let clip = proj * vec4(1.0, 1.0, 1.0, 1.0);
+    approx::assert_relative_eq!(clip, vec4(4.0, 3.0, 4.0, 1.0));
+    approx::assert_relative_eq!(vec3(clip.x, clip.y, clip.z) / clip.w, proj * vec3(1.0, 1.0, 1.0));
+}
@@ -1,9 +1,10 @@
-use crate::{vec2, Matrix2, Scalar, Vector2};
+use crate::{math, vec2, Matrix2, Scalar, Vector2};
 
 /// A rotational transform in two-dimensional space.
 #[repr(transparent)]
 #[derive(PartialEq, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct Rotation2 {
     /// The tangent of half the angle of the rotation (positive values correspond to
@@ -44,10 +45,23 @@ impl Rotation2 {
         tan_half_angle: Scalar::INFINITY,
     };
 
+    /// Constructs a rotation from the tangent of half of its counter-clockwise angle. This may be
+    /// infinite, for a rotation by 180 degrees.
+    #[inline]
+    pub const fn from_tan_half_angle(tan_half_angle: Scalar) -> Self {
+        Self { tan_half_angle }
+    }
+
+    /// Gets the tangent of half of the counter-clockwise angle of this rotation.
+    #[inline]
+    pub const fn tan_half_angle(&self) -> Scalar {
+        self.tan_half_angle
+    }
+
     /// Constructs a rotation which rotates counter-clockwise by the given angle, in radians.
     pub fn from_angle(angle: Scalar) -> Self {
         Self {
-            tan_half_angle: (angle / 2.0).tan(),
+            tan_half_angle: math::tan(angle / 2.0),
         }
     }
 
@@ -83,6 +97,13 @@ impl Default for Rotation2 {
     }
 }
 
+/// Perturbations are right-multiplied rotations, given as counter-clockwise angles in radians.
+impl diffvec::Differentiate<Scalar> for Rotation2 {
+    fn perturb_mut(&mut self, amount: &Scalar) {
+        *self = *self * Rotation2::from_angle(*amount);
+    }
+}
+
 impl core::ops::Mul<Rotation2> for Rotation2 {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
@@ -143,6 +164,14 @@ fn test_compose() {
     }
 }
 
+#[test]
+fn test_perturb() {
+    use diffvec::Differentiate;
+    let mut rot = Rotation2::from_angle(0.5);
+    rot.perturb_mut(&0.25);
+    approx::assert_relative_eq!(rot.tan_half_angle, (0.375 as Scalar).tan(), epsilon = 1e-6);
+}
+
 #[test]
 fn test_consts() {
     let vec = vec2(1.0, 0.2);
@@ -236,3 +265,18 @@ fn test_distribution() {
         worst_density
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use serde_test::{assert_tokens, Token};
+    assert_tokens(
+        &Rotation2::from_tan_half_angle(0.5),
+        &[
+            Token::Struct { name: "Rotation2", len: 1 },
+            Token::Str("tan_half_angle"),
+            Token::F32(0.5),
+            Token::StructEnd,
+        ],
+    );
+}
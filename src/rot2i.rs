@@ -9,16 +9,17 @@ use cantor::Finite;
 #[repr(u8)]
 #[derive(Finite, Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serdere", derive(serdere::Deserialize, serdere::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub enum Rotation2i {
     #[default]
-    #[cfg_attr(feature = "serdere", serde(rename = "xpyp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xpyp"))]
     XpYp = 0,
-    #[cfg_attr(feature = "serdere", serde(rename = "ypxn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "ypxn"))]
     YpXn = 1,
-    #[cfg_attr(feature = "serdere", serde(rename = "xnyn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xnyn"))]
     XnYn = 2,
-    #[cfg_attr(feature = "serdere", serde(rename = "ynxp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "ynxp"))]
     YnXp = 3,
 }
 
@@ -37,7 +38,7 @@ impl Rotation2i {
 
     /// Gets the inverse of this rotation.
     pub fn inverse(&self) -> Self {
-        unsafe { std::mem::transmute::<u8, Self>((4 - *self as u8) & 0b11) }
+        unsafe { core::mem::transmute::<u8, Self>((4 - *self as u8) & 0b11) }
     }
 
     /// Converts this rotation to a [`Rotation2`].
@@ -56,7 +57,7 @@ impl Rotation2i {
             let mut table = [Matrix2::identity(); 4];
             let mut i: u8 = 0;
             while i < 4 {
-                let rot: Rotation2i = unsafe { std::mem::transmute(i) };
+                let rot: Rotation2i = unsafe { core::mem::transmute(i) };
                 table[i as usize] = Matrix2 {
                     x: rot.apply_vec2(vec2(1.0, 0.0)),
                     y: rot.apply_vec2(vec2(0.0, 1.0)),
@@ -92,7 +93,7 @@ impl Rotation2i {
 impl core::ops::Mul<Rotation2i> for Rotation2i {
     type Output = Rotation2i;
     fn mul(self, rhs: Rotation2i) -> Rotation2i {
-        unsafe { std::mem::transmute::<u8, Self>((self as u8 + rhs as u8) & 0b11) }
+        unsafe { core::mem::transmute::<u8, Self>((self as u8 + rhs as u8) & 0b11) }
     }
 }
 
@@ -1,11 +1,13 @@
-use crate::{vec3, Matrix3, Rotation2, Scalar, Vector3};
+use crate::{math, vec3, Matrix3, Rotation2, Scalar, Vector3};
 
 /// A rotation in three-dimensional space.
 #[repr(C)]
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct Rotation3 {
     /// The vector part of the quaternion.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
     x_y_z: Vector3,
 
     /// The scalar part of the quaternion.
@@ -34,11 +36,11 @@ impl Rotation3 {
     pub fn about(axis: Vector3, amount: Rotation2) -> Self {
         let (sin, cos) = amount.angle_sin_cos();
         let (h_sin, h_cos) = if cos > 0.0 {
-            let h_cos = ((1.0 + cos) / 2.0).sqrt();
+            let h_cos = math::sqrt((1.0 + cos) / 2.0);
             let h_sin = sin * h_cos / (1.0 + cos);
             (h_sin, h_cos)
         } else {
-            let h_sin = ((1.0 - cos) / 2.0).sqrt();
+            let h_sin = math::sqrt((1.0 - cos) / 2.0);
             let h_cos = sin * h_sin / (1.0 - cos);
             (h_sin, h_cos)
         };
@@ -52,8 +54,33 @@ impl Rotation3 {
     /// in radians, following the right-hand rule.
     #[inline]
     pub fn from_euler(vec: Vector3) -> Self {
-        let len = vec.norm();
-        Self::about(vec / len, Rotation2::from_angle(len))
+        let angle = vec.norm();
+        let (h_sin, h_cos) = math::sin_cos(angle / 2.0);
+        let h_sinc = if angle > 1e-3 {
+            h_sin / angle
+        } else {
+            // Use a series approximation of `sin(angle / 2) / angle` for small angles
+            0.5 - angle * angle / 48.0
+        };
+        Self::new_unchecked(h_cos, vec.x * h_sinc, vec.y * h_sinc, vec.z * h_sinc)
+    }
+
+    /// Gets the vector whose direction is the axis of this rotation and whose magnitude is its
+    /// angle, in radians, following the right-hand rule. The angle is in \[0, π\]. This is the
+    /// inverse of [`Rotation3::from_euler`].
+    pub fn to_euler(&self) -> Vector3 {
+        let (w, x_y_z) = if self.w < 0.0 {
+            (-self.w, -self.x_y_z)
+        } else {
+            (self.w, self.x_y_z)
+        };
+        let sin = x_y_z.norm();
+        if sin > 1e-4 {
+            x_y_z * (2.0 * math::atan2(sin, w) / sin)
+        } else {
+            // Use a series approximation of `atan(sin / w) / sin` for small angles
+            x_y_z * (2.0 / w) * (1.0 - sin * sin / (3.0 * w * w))
+        }
     }
 
     /// Assuming the given matrix is a rotation, constructs a [`Rotation3`] from it.
@@ -62,7 +89,7 @@ impl Rotation3 {
     pub fn from_matrix(matrix: Matrix3) -> Self {
         let trace = matrix.x.x + matrix.y.y + matrix.z.z;
         if trace > 0.0 {
-            let s = (trace + 1.0).sqrt();
+            let s = math::sqrt(trace + 1.0);
             let inv_s = 0.5 / s;
             Self::new_unchecked(
                 0.5 * s,
@@ -71,7 +98,7 @@ impl Rotation3 {
                 (matrix.x.y - matrix.y.x) * inv_s,
             )
         } else if matrix.x.x >= matrix.y.y && matrix.x.x >= matrix.z.z {
-            let s = (1.0 + matrix.x.x - matrix.y.y - matrix.z.z).sqrt();
+            let s = math::sqrt(1.0 + matrix.x.x - matrix.y.y - matrix.z.z);
             let inv_s = 0.5 / s;
             Self::new_unchecked(
                 (matrix.y.z - matrix.z.y) * inv_s,
@@ -80,7 +107,7 @@ impl Rotation3 {
                 (matrix.z.x + matrix.x.z) * inv_s,
             )
         } else if matrix.y.y > matrix.z.z {
-            let s = (1.0 + matrix.y.y - matrix.z.z - matrix.x.x).sqrt();
+            let s = math::sqrt(1.0 + matrix.y.y - matrix.z.z - matrix.x.x);
             let inv_s = 0.5 / s;
             Self::new_unchecked(
                 (matrix.z.x - matrix.x.z) * inv_s,
@@ -89,7 +116,7 @@ impl Rotation3 {
                 (matrix.y.z + matrix.z.y) * inv_s,
             )
         } else {
-            let s = (1.0 + matrix.z.z - matrix.x.x - matrix.y.y).sqrt();
+            let s = math::sqrt(1.0 + matrix.z.z - matrix.x.x - matrix.y.y);
             let inv_s = 0.5 / s;
             Self::new_unchecked(
                 (matrix.x.y - matrix.y.x) * inv_s,
@@ -100,6 +127,18 @@ impl Rotation3 {
         }
     }
 
+    /// Gets the scalar part of the quaternion for this rotation.
+    #[inline]
+    pub const fn w(&self) -> Scalar {
+        self.w
+    }
+
+    /// Gets the vector part of the quaternion for this rotation.
+    #[inline]
+    pub const fn x_y_z(&self) -> Vector3 {
+        self.x_y_z
+    }
+
     /// Gets the inverse of this rotation.
     #[inline]
     pub fn inverse(&self) -> Self {
@@ -117,6 +156,33 @@ impl Default for Rotation3 {
     }
 }
 
+/// Deserialization checks that the quaternion is normalized, up to a tolerance which is loose
+/// enough to accept quaternions that were normalized in single precision and written as decimal
+/// text.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rotation3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Rotation3")]
+        struct Raw {
+            #[serde(with = "crate::serde_util::Vector3Def")]
+            x_y_z: Vector3,
+            w: Scalar,
+        }
+        let Raw { x_y_z, w } = Raw::deserialize(deserializer)?;
+        crate::serde_util::check_quaternion((w * w + x_y_z.norm_squared()) as f64)?;
+        Ok(Self { x_y_z, w })
+    }
+}
+
+/// Perturbations are right-multiplied rotations, given as rotation vectors (see
+/// [`Rotation3::from_euler`]) in the local frame of the rotation.
+impl diffvec::Differentiate<Vector3> for Rotation3 {
+    fn perturb_mut(&mut self, amount: &Vector3) {
+        *self = *self * Rotation3::from_euler(*amount);
+    }
+}
+
 impl core::ops::Mul<Rotation3> for Rotation3 {
     type Output = Rotation3;
     fn mul(self, rhs: Rotation3) -> Rotation3 {
@@ -134,7 +200,7 @@ impl core::ops::Mul<Rotation3> for Rotation3 {
     }
 }
 
-impl std::ops::Mul<Vector3> for Rotation3 {
+impl core::ops::Mul<Vector3> for Rotation3 {
     type Output = Vector3;
     fn mul(self, rhs: Vector3) -> Vector3 {
         // TODO: Better implementation
@@ -217,9 +283,72 @@ fn test_compose_2() {
     approx::assert_abs_diff_eq!(a * b * c * x, a * (b * (c * x)), epsilon = 1e-5);
 }
 
+#[test]
+fn test_euler_roundtrip() {
+    for vec in [vec3(1.0, 2.0, 0.5), vec3(-0.1, 0.0, 0.2), vec3(1e-5, 0.0, -2e-5)] {
+        approx::assert_relative_eq!(Rotation3::from_euler(vec).to_euler(), vec, epsilon = 1e-5);
+    }
+}
+
+#[test]
+fn test_from_euler_zero() {
+    assert_eq!(Rotation3::from_euler(vec3(0.0, 0.0, 0.0)), Rotation3::IDENTITY);
+}
+
+#[test]
+fn test_perturb() {
+    use diffvec::Differentiate;
+    let rot = Rotation3::from_euler(vec3(0.3, -0.2, 1.1));
+    let mut res = rot;
+    res.perturb_mut(&vec3(0.0, 0.0, 0.0));
+    approx::assert_relative_eq!(res, rot);
+    res.perturb_mut(&vec3(0.0, 0.5, 0.0));
+    let axis = vec3(0.0, 1.0, 0.0);
+    approx::assert_relative_eq!(res * axis, rot * axis, epsilon = 1e-5);
+    approx::assert_relative_eq!(
+        res,
+        rot * Rotation3::about(axis, Rotation2::from_angle(0.5)),
+        epsilon = 1e-5
+    );
+}
+
 #[test]
 fn test_matrix_roundtrip() {
     let rot = Rotation3::from_euler(vec3(1.0, 2.0, 3.0));
     let mat: Matrix3 = rot.into();
     approx::assert_abs_diff_eq!(rot, Rotation3::from_matrix(mat), epsilon = 1e-6);
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+    let tokens = |x: Scalar, y: Scalar, w: Scalar| {
+        [
+            Token::Struct { name: "Rotation3", len: 2 },
+            Token::Str("x_y_z"),
+            Token::Struct { name: "Vector3", len: 3 },
+            Token::Str("x"),
+            Token::F32(x),
+            Token::Str("y"),
+            Token::F32(y),
+            Token::Str("z"),
+            Token::F32(0.0),
+            Token::StructEnd,
+            Token::Str("w"),
+            Token::F32(w),
+            Token::StructEnd,
+        ]
+    };
+    assert_tokens(&Rotation3::new_unchecked(0.6, 0.0, 0.8, 0.0), &tokens(0.0, 0.8, 0.6));
+
+    // Non-normalized quaternions are rejected
+    assert_de_tokens_error::<Rotation3>(
+        &tokens(0.5, 0.0, 1.0),
+        "rotation quaternion is not normalized",
+    );
+    assert_de_tokens_error::<Rotation3>(
+        &tokens(0.0, 0.0, 0.0),
+        "rotation quaternion is not normalized",
+    );
+}
@@ -9,56 +9,57 @@ use cantor::Finite;
 #[repr(u8)]
 #[derive(Finite, Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serdere", derive(serdere::Deserialize, serdere::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub enum Rotation3i {
     #[default]
-    #[cfg_attr(feature = "serdere", serde(rename = "xpypzp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xpypzp"))]
     XpYpZp = 0,
-    #[cfg_attr(feature = "serdere", serde(rename = "ypxpzn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "ypxpzn"))]
     YpXpZn = 1,
-    #[cfg_attr(feature = "serdere", serde(rename = "ypxnzp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "ypxnzp"))]
     YpXnZp = 2,
-    #[cfg_attr(feature = "serdere", serde(rename = "xnypzn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xnypzn"))]
     XnYpZn = 3,
-    #[cfg_attr(feature = "serdere", serde(rename = "xnynzp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xnynzp"))]
     XnYnZp = 4,
-    #[cfg_attr(feature = "serdere", serde(rename = "ynxnzn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "ynxnzn"))]
     YnXnZn = 5,
-    #[cfg_attr(feature = "serdere", serde(rename = "ynxpzp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "ynxpzp"))]
     YnXpZp = 6,
-    #[cfg_attr(feature = "serdere", serde(rename = "xpynzn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xpynzn"))]
     XpYnZn = 7,
-    #[cfg_attr(feature = "serdere", serde(rename = "ypzpxp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "ypzpxp"))]
     YpZpXp = 8,
-    #[cfg_attr(feature = "serdere", serde(rename = "xpzpyn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xpzpyn"))]
     XpZpYn = 9,
-    #[cfg_attr(feature = "serdere", serde(rename = "xnzpyp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xnzpyp"))]
     XnZpYp = 10,
-    #[cfg_attr(feature = "serdere", serde(rename = "ypznxn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "ypznxn"))]
     YpZnXn = 11,
-    #[cfg_attr(feature = "serdere", serde(rename = "ynzpxn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "ynzpxn"))]
     YnZpXn = 12,
-    #[cfg_attr(feature = "serdere", serde(rename = "xnznyn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xnznyn"))]
     XnZnYn = 13,
-    #[cfg_attr(feature = "serdere", serde(rename = "xpznyp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xpznyp"))]
     XpZnYp = 14,
-    #[cfg_attr(feature = "serdere", serde(rename = "ynznxp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "ynznxp"))]
     YnZnXp = 15,
-    #[cfg_attr(feature = "serdere", serde(rename = "zpxpyp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "zpxpyp"))]
     ZpXpYp = 16,
-    #[cfg_attr(feature = "serdere", serde(rename = "znypxp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "znypxp"))]
     ZnYpXp = 17,
-    #[cfg_attr(feature = "serdere", serde(rename = "zpypxn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "zpypxn"))]
     ZpYpXn = 18,
-    #[cfg_attr(feature = "serdere", serde(rename = "znxnyp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "znxnyp"))]
     ZnXnYp = 19,
-    #[cfg_attr(feature = "serdere", serde(rename = "zpxnyn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "zpxnyn"))]
     ZpXnYn = 20,
-    #[cfg_attr(feature = "serdere", serde(rename = "znynxn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "znynxn"))]
     ZnYnXn = 21,
-    #[cfg_attr(feature = "serdere", serde(rename = "zpynxp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "zpynxp"))]
     ZpYnXp = 22,
-    #[cfg_attr(feature = "serdere", serde(rename = "znxpyn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "znxpyn"))]
     ZnXpYn = 23,
 }
 
@@ -72,10 +73,10 @@ impl Rotation3i {
             let mut table = [Rotation3i::XpYpZp; 24];
             let mut i: u8 = 0;
             while i < 24 {
-                let rot: Rotation3i = unsafe { std::mem::transmute(i) };
+                let rot: Rotation3i = unsafe { core::mem::transmute(i) };
                 let mut j: u8 = 0;
                 while j < 24 {
-                    let inv: Rotation3i = unsafe { std::mem::transmute(j) };
+                    let inv: Rotation3i = unsafe { core::mem::transmute(j) };
                     const TEST: Vector3i = vec3i(1, 2, 3);
                     if vec3i_eq(inv.apply_vec3i(rot.apply_vec3i(TEST)), TEST) {
                         table[i as usize] = inv;
@@ -96,13 +97,13 @@ impl Rotation3i {
             let mut table = [[Rotation3i::XpYpZp; 24]; 24];
             let mut i: u8 = 0;
             while i < 24 {
-                let rot_a: Rotation3i = unsafe { std::mem::transmute(i) };
+                let rot_a: Rotation3i = unsafe { core::mem::transmute(i) };
                 let mut j: u8 = 0;
                 while j < 24 {
-                    let rot_b: Rotation3i = unsafe { std::mem::transmute(j) };
+                    let rot_b: Rotation3i = unsafe { core::mem::transmute(j) };
                     let mut k: u8 = 0;
                     while k < 24 {
-                        let rot_c: Rotation3i = unsafe { std::mem::transmute(k) };
+                        let rot_c: Rotation3i = unsafe { core::mem::transmute(k) };
                         const TEST: Vector3i = vec3i(1, 2, 3);
                         if vec3i_eq(
                             rot_a.apply_vec3i(rot_b.apply_vec3i(TEST)),
@@ -124,7 +125,7 @@ impl Rotation3i {
 
     /// Converts this rotation to a [`Rotation3`].
     const fn to_rot3(self) -> Rotation3 {
-        const SQ: Scalar = std::f32::consts::SQRT_2 / 2.0;
+        const SQ: Scalar = core::f32::consts::SQRT_2 / 2.0;
         const TABLE: [Rotation3; 24] = [
             Rotation3::new_unchecked(1.0, 0.0, 0.0, 0.0),
             Rotation3::new_unchecked(0.0, SQ, SQ, 0.0),
@@ -160,7 +161,7 @@ impl Rotation3i {
             let mut table = [Matrix3::identity(); 24];
             let mut i: u8 = 0;
             while i < 24 {
-                let rot: Rotation3i = unsafe { std::mem::transmute(i) };
+                let rot: Rotation3i = unsafe { core::mem::transmute(i) };
                 table[i as usize] = Matrix3 {
                     x: rot.apply_vec3(vec3(1.0, 0.0, 0.0)),
                     y: rot.apply_vec3(vec3(0.0, 1.0, 0.0)),
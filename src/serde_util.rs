@@ -0,0 +1,126 @@
+//! Contains remote definitions for serializing vector and matrix types with [`serde`]. These types
+//! come from `diffvec`, which doesn't support `serde`, so fields of those types need to use the
+//! definitions in this module:
+//!
+//! ```
+//! use diffgeom::Vector3;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Particle {
+//!     #[serde(with = "diffgeom::serde_util::Vector3Def")]
+//!     position: Vector3,
+//! }
+//! ```
+//!
+//! Each type is serialized as a struct with the same name and fields as the original type.
+use crate::{Matrix2, Matrix3, Matrix4, Scalar, Vector2, Vector3, Vector4};
+
+/// A remote definition for serializing a [`Vector2`].
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Vector2", rename = "Vector2")]
+pub struct Vector2Def {
+    x: Scalar,
+    y: Scalar,
+}
+
+/// A remote definition for serializing a [`Vector3`].
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Vector3", rename = "Vector3")]
+pub struct Vector3Def {
+    x: Scalar,
+    y: Scalar,
+    z: Scalar,
+}
+
+/// A remote definition for serializing a [`Vector4`].
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Vector4", rename = "Vector4")]
+pub struct Vector4Def {
+    x: Scalar,
+    y: Scalar,
+    z: Scalar,
+    w: Scalar,
+}
+
+/// A remote definition for serializing a [`Matrix2`].
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Matrix2", rename = "Matrix2")]
+pub struct Matrix2Def {
+    #[serde(with = "Vector2Def")]
+    x: Vector2,
+    #[serde(with = "Vector2Def")]
+    y: Vector2,
+}
+
+/// A remote definition for serializing a [`Matrix3`].
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Matrix3", rename = "Matrix3")]
+pub struct Matrix3Def {
+    #[serde(with = "Vector3Def")]
+    x: Vector3,
+    #[serde(with = "Vector3Def")]
+    y: Vector3,
+    #[serde(with = "Vector3Def")]
+    z: Vector3,
+}
+
+/// A remote definition for serializing a [`Matrix4`].
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Matrix4", rename = "Matrix4")]
+pub struct Matrix4Def {
+    #[serde(with = "Vector4Def")]
+    x: Vector4,
+    #[serde(with = "Vector4Def")]
+    y: Vector4,
+    #[serde(with = "Vector4Def")]
+    z: Vector4,
+    #[serde(with = "Vector4Def")]
+    w: Vector4,
+}
+
+/// The maximum deviation of the squared norm of a deserialized quaternion from 1.
+///
+/// This is shared between single and double precision so that data can be exchanged between
+/// them. It is far above single-precision rounding error, so that it accepts quaternions which
+/// were normalized in single precision and written with at least 6 significant digits (a squared
+/// norm error of at most about 2e-6), while still rejecting quaternions which were never
+/// normalized.
+pub(crate) const QUATERNION_NORM_SQR_TOLERANCE: f64 = 1e-5;
+
+/// Checks that a deserialized quaternion with the given squared norm is normalized, up to
+/// [`QUATERNION_NORM_SQR_TOLERANCE`].
+pub(crate) fn check_quaternion<E: serde::de::Error>(norm_sqr: f64) -> Result<(), E> {
+    if (norm_sqr - 1.0).abs() <= QUATERNION_NORM_SQR_TOLERANCE {
+        Ok(())
+    } else {
+        Err(E::custom("rotation quaternion is not normalized"))
+    }
+}
+
+/// Implements [`serde::Deserialize`] for an axis-aligned box type with private `min` and `max`
+/// fields, rejecting boxes whose minimum exceeds their maximum along any of the given axes. This
+/// also rejects NaN coordinates.
+macro_rules! impl_deserialize_box {
+    ($box:ty, $name:literal, $vec:ty $(as $with:literal)?, [$($axis:ident),*]) => {
+        impl<'de> serde::Deserialize<'de> for $box {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(serde::Deserialize)]
+                #[serde(rename = $name)]
+                struct Raw {
+                    $(#[serde(with = $with)])?
+                    min: $vec,
+                    $(#[serde(with = $with)])?
+                    max: $vec,
+                }
+                let Raw { min, max } = Raw::deserialize(deserializer)?;
+                if $(min.$axis <= max.$axis)&&* {
+                    Ok(Self { min, max })
+                } else {
+                    Err(serde::de::Error::custom("box minimum exceeds maximum"))
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use impl_deserialize_box;
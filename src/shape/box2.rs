@@ -4,9 +4,12 @@ use crate::{vec2, Scalar, Vector2};
 #[repr(C)]
 #[derive(Default, PartialEq, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct Box2 {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector2Def"))]
     min: Vector2,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector2Def"))]
     max: Vector2,
 }
 
@@ -91,3 +94,43 @@ impl Box2 {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+crate::serde_util::impl_deserialize_box!(
+    Box2,
+    "Box2",
+    Vector2 as "crate::serde_util::Vector2Def",
+    [x, y]
+);
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+    let tokens = |min_x: Scalar, max_x: Scalar| {
+        [
+            Token::Struct { name: "Box2", len: 2 },
+            Token::Str("min"),
+            Token::Struct { name: "Vector2", len: 2 },
+            Token::Str("x"),
+            Token::F32(min_x),
+            Token::Str("y"),
+            Token::F32(0.0),
+            Token::StructEnd,
+            Token::Str("max"),
+            Token::Struct { name: "Vector2", len: 2 },
+            Token::Str("x"),
+            Token::F32(max_x),
+            Token::Str("y"),
+            Token::F32(1.0),
+            Token::StructEnd,
+            Token::StructEnd,
+        ]
+    };
+    let bx = Box2::from_min_max(vec2(-2.0, 0.0), vec2(2.0, 1.0));
+    assert_tokens(&bx, &tokens(-2.0, 2.0));
+
+    // The minimum may not exceed the maximum, and NaN coordinates are rejected
+    assert_de_tokens_error::<Box2>(&tokens(2.0, -2.0), "box minimum exceeds maximum");
+    assert_de_tokens_error::<Box2>(&tokens(Scalar::NAN, 2.0), "box minimum exceeds maximum");
+}
@@ -1,5 +1,5 @@
 use crate::{vec2i, Vector2i};
-use std::num::NonZeroU32;
+use core::num::NonZeroU32;
 
 /// An axis-aligned rectangle in discrete two-dimensional space.
 ///
@@ -7,6 +7,7 @@ use std::num::NonZeroU32;
 #[repr(C)]
 #[derive(Default, PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct Box2i {
     /// The inclusive minimum coordinates of the box.
@@ -94,6 +95,9 @@ impl Box2i {
     }
 }
 
+#[cfg(feature = "serde")]
+crate::serde_util::impl_deserialize_box!(Box2i, "Box2i", Vector2i, [x, y]);
+
 /// Describes the size of a [`Box2i`]. Each component must be positive.
 #[repr(C)]
 #[derive(Default, PartialEq, Eq, Copy, Clone, Hash)]
@@ -165,8 +169,8 @@ pub const fn size2i(x: u32, y: u32) -> Size2i {
     )
 }
 
-impl std::fmt::Debug for Size2i {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for Size2i {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("size2i")
             .field(&(self.x_minus_1 as usize + 1))
             .field(&(self.y_minus_1 as usize + 1))
@@ -174,6 +178,45 @@ impl std::fmt::Debug for Size2i {
     }
 }
 
+/// The serialized form of a [`Size2i`], which stores the actual size in each direction.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Size2i")]
+struct Size2iRepr {
+    x: u64,
+    y: u64,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Size2i {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Size2iRepr {
+            x: self.x_minus_1 as u64 + 1,
+            y: self.y_minus_1 as u64 + 1,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Size2i {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Size2iRepr { x, y } = Size2iRepr::deserialize(deserializer)?;
+        let component = |value: u64| -> Result<u32, D::Error> {
+            if value == 0 {
+                Err(serde::de::Error::custom(SIZE_COMPONENT_ZERO_ERROR))
+            } else {
+                u32::try_from(value - 1)
+                    .map_err(|_| serde::de::Error::custom(SIZE_OVERFLOW_ERROR))
+            }
+        };
+        Ok(Self {
+            x_minus_1: component(x)?,
+            y_minus_1: component(y)?,
+        })
+    }
+}
+
 impl core::ops::Add<Size2i> for Size2i {
     type Output = Size2i;
     fn add(self, rhs: Size2i) -> Size2i {
@@ -190,3 +233,63 @@ impl core::ops::AddAssign<Size2i> for Size2i {
         self.y_minus_1 += rhs.y_minus_1 + 1;
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_box() {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+    let tokens = |min: [i32; 2], max: [i32; 2]| {
+        [
+            Token::Struct { name: "Box2i", len: 2 },
+            Token::Str("min"),
+            Token::Struct { name: "Vector2i", len: 2 },
+            Token::Str("x"),
+            Token::I32(min[0]),
+            Token::Str("y"),
+            Token::I32(min[1]),
+            Token::StructEnd,
+            Token::Str("max"),
+            Token::Struct { name: "Vector2i", len: 2 },
+            Token::Str("x"),
+            Token::I32(max[0]),
+            Token::Str("y"),
+            Token::I32(max[1]),
+            Token::StructEnd,
+            Token::StructEnd,
+        ]
+    };
+    let bx = Box2i::from_min_max(vec2i(-1, 2), vec2i(3, 2));
+    assert_tokens(&bx, &tokens([-1, 2], [3, 2]));
+
+    // The minimum may not exceed the maximum along any axis
+    assert_de_tokens_error::<Box2i>(&tokens([-1, 2], [3, 1]), "box minimum exceeds maximum");
+    assert_de_tokens_error::<Box2i>(&tokens([4, 2], [3, 2]), "box minimum exceeds maximum");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_size() {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+    let tokens = |x: u64, y: u64| {
+        [
+            Token::Struct { name: "Size2i", len: 2 },
+            Token::Str("x"),
+            Token::U64(x),
+            Token::Str("y"),
+            Token::U64(y),
+            Token::StructEnd,
+        ]
+    };
+    let size = Size2i::new(NonZeroU32::new(3).unwrap(), NonZeroU32::new(1).unwrap());
+    assert_tokens(&size, &tokens(3, 1));
+    let largest = Size2i {
+        x_minus_1: u32::MAX,
+        y_minus_1: u32::MAX,
+    };
+    assert_tokens(&largest, &tokens(1 << 32, 1 << 32));
+
+    // Each component must be positive, and fit in a `u32`
+    assert_de_tokens_error::<Size2i>(&tokens(0, 1), SIZE_COMPONENT_ZERO_ERROR);
+    assert_de_tokens_error::<Size2i>(&tokens(3, 0), SIZE_COMPONENT_ZERO_ERROR);
+    assert_de_tokens_error::<Size2i>(&tokens(1, (1 << 32) + 1), SIZE_OVERFLOW_ERROR);
+}
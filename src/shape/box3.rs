@@ -0,0 +1,130 @@
+use crate::{vec3, Scalar, Vector3};
+
+/// An axis-aligned box in three-dimensional space.
+#[repr(C)]
+#[derive(Default, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Box3 {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
+    min: Vector3,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
+    max: Vector3,
+}
+
+impl Box3 {
+    /// A [`Box3`] that contains all points.
+    pub const ALL: Box3 = Self {
+        min: vec3(Scalar::NEG_INFINITY, Scalar::NEG_INFINITY, Scalar::NEG_INFINITY),
+        max: vec3(Scalar::INFINITY, Scalar::INFINITY, Scalar::INFINITY),
+    };
+
+    /// Constructs a [`Box3`] which contains only the given point.
+    #[inline]
+    pub const fn only(point: Vector3) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// Constructs a [`Box3`] from its minimum and maximum coordinates.
+    #[inline]
+    pub const fn from_min_max(min: Vector3, max: Vector3) -> Self {
+        Self { min, max }
+    }
+
+    /// The minimum coordinates of the box.
+    #[inline]
+    pub const fn min(&self) -> Vector3 {
+        self.min
+    }
+
+    /// The maximum coordinates of the box.
+    #[inline]
+    pub const fn max(&self) -> Vector3 {
+        self.max
+    }
+
+    /// The center of the box.
+    #[inline]
+    pub fn center(&self) -> Vector3 {
+        (self.min + self.max) / 2.0
+    }
+
+    /// Gets the corners of the box. The X, Y and Z coordinates of the corner at index `i` are
+    /// taken from the maximum coordinates if bits 0, 1 and 2 of `i` are set, respectively.
+    #[inline]
+    pub fn corners(&self) -> [Vector3; 8] {
+        let (a, b) = (self.min, self.max);
+        [
+            vec3(a.x, a.y, a.z),
+            vec3(b.x, a.y, a.z),
+            vec3(a.x, b.y, a.z),
+            vec3(b.x, b.y, a.z),
+            vec3(a.x, a.y, b.z),
+            vec3(b.x, a.y, b.z),
+            vec3(a.x, b.y, b.z),
+            vec3(b.x, b.y, b.z),
+        ]
+    }
+
+    /// Determines whether this box contains the given point.
+    #[inline]
+    pub const fn contains(&self, point: Vector3) -> bool {
+        self.min.x <= point.x
+            && self.min.y <= point.y
+            && self.min.z <= point.z
+            && point.x <= self.max.x
+            && point.y <= self.max.y
+            && point.z <= self.max.z
+    }
+
+    /// Determines whether this box has any points in common with the given box.
+    #[inline]
+    pub const fn overlaps(&self, other: Box3) -> bool {
+        self.min.x <= other.max.x
+            && self.min.y <= other.max.y
+            && self.min.z <= other.max.z
+            && other.min.x <= self.max.x
+            && other.min.y <= self.max.y
+            && other.min.z <= self.max.z
+    }
+
+    /// Gets the smallest box that contains both this box and the given box.
+    #[inline]
+    pub fn bound(&self, other: Box3) -> Box3 {
+        Self {
+            min: vec3(
+                self.min.x.min(other.min.x),
+                self.min.y.min(other.min.y),
+                self.min.z.min(other.min.z),
+            ),
+            max: vec3(
+                self.max.x.max(other.max.x),
+                self.max.y.max(other.max.y),
+                self.max.z.max(other.max.z),
+            ),
+        }
+    }
+
+    /// Gets the smallest box that contains all of the given boxes, or returns [`None`] if the
+    /// iterator is empty.
+    #[inline]
+    pub fn bound_many(mut boxes: impl Iterator<Item = Box3>) -> Option<Box3> {
+        let mut bx = boxes.next()?;
+        for next_bx in boxes {
+            bx = bx.bound(next_bx);
+        }
+        Some(bx)
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde_util::impl_deserialize_box!(
+    Box3,
+    "Box3",
+    Vector3 as "crate::serde_util::Vector3Def",
+    [x, y, z]
+);
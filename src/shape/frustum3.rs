@@ -0,0 +1,361 @@
+use super::{Box3, Plane3, Sphere3};
+use crate::{vec3, vec4, Motion3, Projective3, Scalar, Vector3, Vector4};
+
+/// The region of three-dimensional space which is visible through a projection, i.e. the set of
+/// points which are mapped into normalized device coordinates by a [`Projective3`].
+///
+/// This follows the conventions of the [`crate::conv`] module: the visible region of normalized
+/// device coordinates is \[-1, 1\] × \[-1, 1\] × \[0, 1\], with a Z value of 1 on the near plane.
+/// The far plane may be at infinity. Projections which target other depth ranges, such as those
+/// built by [`crate::conv::Convention::perspective`] for [`crate::conv::Convention::OPENGL`], are
+/// not supported.
+///
+/// This doesn't implement `serde`'s traits, since its planes and corners are derived from each
+/// other and would need to be checked for consistency. Serialize the projection instead.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct Frustum3 {
+    /// The planes bounding the frustum, in the order left, right, bottom, top, far, near. The
+    /// inside of the frustum is in front of all planes.
+    planes: [Plane3; 6],
+
+    /// The homogeneous coordinates of the corners of the frustum. The corner at index `i` is at
+    /// the positive X, Y and far extremes if bits 0, 1 and 2 of `i` are set, respectively.
+    ///
+    /// The W component of each corner is either 1, or 0 for corners at infinity.
+    corners: [Vector4; 8],
+}
+
+/// Describes how a shape is positioned relative to a region.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Containment {
+    /// The shape is entirely outside the region.
+    #[cfg_attr(feature = "serde", serde(rename = "outside"))]
+    Outside,
+
+    /// The shape may be partially inside the region.
+    #[cfg_attr(feature = "serde", serde(rename = "intersecting"))]
+    Intersecting,
+
+    /// The shape is entirely inside the region.
+    #[cfg_attr(feature = "serde", serde(rename = "inside"))]
+    Inside,
+}
+
+impl Frustum3 {
+    /// Constructs the [`Frustum3`] for the given projection.
+    ///
+    /// For a projection from view space, this will be in view space. Use
+    /// [`Frustum3::from_view_proj`], or compose the projection with a world-to-view transform,
+    /// to get the frustum in world space.
+    pub fn new(proj: &Projective3) -> Self {
+        let (r_0, r_1, r_2, r_3) = (proj.row(0), proj.row(1), proj.row(2), proj.row(3));
+        let plane = |v: Vector4| {
+            let normal = vec3(v.x, v.y, v.z);
+            if normal.norm_squared() > 0.0 {
+                Plane3::new(normal, v.w).normalize()
+            } else {
+                // Degenerate plane (e.g. a far plane at infinity) which excludes nothing
+                Plane3::new(normal, Scalar::INFINITY)
+            }
+        };
+        let planes = [
+            plane(r_3 + r_0),
+            plane(r_3 - r_0),
+            plane(r_3 + r_1),
+            plane(r_3 - r_1),
+            plane(r_2),
+            plane(r_3 - r_2),
+        ];
+        let inv = proj.inverse();
+        let corners = core::array::from_fn(|i| {
+            let x = if i & 0b001 != 0 { 1.0 } else { -1.0 };
+            let y = if i & 0b010 != 0 { 1.0 } else { -1.0 };
+            let z = if i & 0b100 != 0 { 0.0 } else { 1.0 };
+            let h = inv * vec4(x, y, z, 1.0);
+            let xyz = vec3(h.x, h.y, h.z);
+            if h.w.abs() <= 1e-6 * xyz.norm() {
+                let dir = xyz.normalize();
+                vec4(dir.x, dir.y, dir.z, 0.0)
+            } else {
+                vec4(h.x / h.w, h.y / h.w, h.z / h.w, 1.0)
+            }
+        });
+        Self { planes, corners }
+    }
+
+    /// Constructs the world-space [`Frustum3`] for the given view-to-NDC projection and
+    /// world-to-view transform.
+    #[inline]
+    pub fn from_view_proj(proj: &Projective3, view: Motion3) -> Self {
+        Self::new(&(*proj * view))
+    }
+
+    /// Gets the planes bounding this frustum, in the order left, right, bottom, top, far, near.
+    /// The inside of the frustum is in front of all planes.
+    ///
+    /// If the far plane is at infinity, it will have a zero normal and an infinite offset.
+    #[inline]
+    pub fn planes(&self) -> &[Plane3; 6] {
+        &self.planes
+    }
+
+    /// Gets the corners of this frustum, or returns [`None`] if the far plane is at infinity.
+    /// The corner at index `i` is at the positive X, Y and far extremes if bits 0, 1 and 2 of `i`
+    /// are set, respectively.
+    pub fn corners(&self) -> Option<[Vector3; 8]> {
+        if self.corners.iter().all(|c| c.w != 0.0) {
+            Some(self.corners.map(|c| vec3(c.x, c.y, c.z)))
+        } else {
+            None
+        }
+    }
+
+    /// Determines whether this frustum contains the given point.
+    #[inline]
+    pub fn contains(&self, point: Vector3) -> bool {
+        self.planes.iter().all(|plane| plane.dist(point) >= 0.0)
+    }
+
+    /// Determines how the given sphere is positioned relative to this frustum, using only the
+    /// planes of the frustum.
+    ///
+    /// This is conservative: it may return [`Containment::Intersecting`] for spheres which are
+    /// outside the frustum near its edges and corners, but will never return
+    /// [`Containment::Outside`] for a sphere which intersects the frustum.
+    pub fn test_sphere(&self, sphere: &Sphere3) -> Containment {
+        let mut res = Containment::Inside;
+        for plane in self.planes.iter() {
+            let dist = plane.dist(sphere.center);
+            if dist < -sphere.radius {
+                return Containment::Outside;
+            } else if dist < sphere.radius {
+                res = Containment::Intersecting;
+            }
+        }
+        res
+    }
+
+    /// Determines how the given sphere is positioned relative to this frustum.
+    ///
+    /// Unlike [`Frustum3::test_sphere`], this will only return [`Containment::Intersecting`] if
+    /// the sphere actually intersects the boundary of the frustum.
+    pub fn test_sphere_exact(&self, sphere: &Sphere3) -> Containment {
+        let res = self.test_sphere(sphere);
+        if res != Containment::Intersecting || self.contains(sphere.center) {
+            return res;
+        }
+
+        // Check for separating axes between the center and the corners and edges of the frustum
+        let center = sphere.center;
+        let radius = sphere.radius;
+        let separates = |axis: Vector3| {
+            let len = axis.norm();
+            if len > 0.0 {
+                let (min, max) = self.project(axis);
+                let c = axis.dot(&center);
+                min > c + radius * len || max < c - radius * len
+            } else {
+                false
+            }
+        };
+        for corner in self.corners.iter() {
+            if corner.w != 0.0 && separates(vec3(corner.x, corner.y, corner.z) - center) {
+                return Containment::Outside;
+            }
+        }
+        for (a, b) in self.edges() {
+            let closest = if b.w == 0.0 {
+                // Edge is a ray from `a` towards `b`
+                let dir = vec3(b.x, b.y, b.z);
+                let a = vec3(a.x, a.y, a.z);
+                a + dir * (center - a).dot(&dir).max(0.0)
+            } else {
+                let a = vec3(a.x, a.y, a.z);
+                let b = vec3(b.x, b.y, b.z);
+                let dir = b - a;
+                let t = (center - a).dot(&dir) / dir.norm_squared();
+                a + dir * t.clamp(0.0, 1.0)
+            };
+            if separates(closest - center) {
+                return Containment::Outside;
+            }
+        }
+        Containment::Intersecting
+    }
+
+    /// Determines how the given box is positioned relative to this frustum, using only the
+    /// planes of the frustum.
+    ///
+    /// This is conservative: it may return [`Containment::Intersecting`] for boxes which are
+    /// outside the frustum near its edges and corners, but will never return
+    /// [`Containment::Outside`] for a box which intersects the frustum.
+    pub fn test_box(&self, bx: &Box3) -> Containment {
+        let mut res = Containment::Inside;
+        let (min, max) = (bx.min(), bx.max());
+        for plane in self.planes.iter() {
+            let n = plane.normal;
+            let (p_x, n_x) = if n.x >= 0.0 { (max.x, min.x) } else { (min.x, max.x) };
+            let (p_y, n_y) = if n.y >= 0.0 { (max.y, min.y) } else { (min.y, max.y) };
+            let (p_z, n_z) = if n.z >= 0.0 { (max.z, min.z) } else { (min.z, max.z) };
+            if plane.dist(vec3(p_x, p_y, p_z)) < 0.0 {
+                return Containment::Outside;
+            } else if plane.dist(vec3(n_x, n_y, n_z)) < 0.0 {
+                res = Containment::Intersecting;
+            }
+        }
+        res
+    }
+
+    /// Determines how the given box is positioned relative to this frustum.
+    ///
+    /// Unlike [`Frustum3::test_box`], this will only return [`Containment::Intersecting`] if
+    /// the box actually intersects the boundary of the frustum. This is done using the
+    /// separating axis theorem, which is significantly more expensive.
+    pub fn test_box_exact(&self, bx: &Box3) -> Containment {
+        let res = self.test_box(bx);
+        if res != Containment::Intersecting {
+            return res;
+        }
+        let center = bx.center();
+        let half = bx.max() - center;
+        let separates = |axis: Vector3| {
+            let (min, max) = self.project(axis);
+            let c = axis.dot(&center);
+            let r = axis.x.abs() * half.x + axis.y.abs() * half.y + axis.z.abs() * half.z;
+            min > c + r || max < c - r
+        };
+        let box_axes = [vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0)];
+        if box_axes.iter().any(|axis| separates(*axis)) {
+            return Containment::Outside;
+        }
+
+        // `test_box` only checks each side of the frustum against its own plane, so the face
+        // normals still need to be checked as full axes
+        for plane in self.planes.iter() {
+            if plane.normal.norm_squared() > 0.0 && separates(plane.normal) {
+                return Containment::Outside;
+            }
+        }
+        for (a, b) in self.edges() {
+            let dir = if b.w == 0.0 {
+                vec3(b.x, b.y, b.z)
+            } else {
+                vec3(b.x - a.x, b.y - a.y, b.z - a.z)
+            };
+            for axis in box_axes.iter() {
+                let axis = axis.cross(&dir);
+                if axis.norm_squared() > 0.0 && separates(axis) {
+                    return Containment::Outside;
+                }
+            }
+        }
+        Containment::Intersecting
+    }
+
+    /// Iterates over the edges of this frustum. The first corner of each edge is always finite.
+    /// Edges between two corners at infinity are excluded.
+    fn edges(&self) -> impl Iterator<Item = (Vector4, Vector4)> + '_ {
+        (0..8).flat_map(move |i| {
+            [0b001, 0b010, 0b100].into_iter().filter_map(move |bit| {
+                if i & bit != 0 {
+                    return None;
+                }
+                let a = self.corners[i];
+                let b = self.corners[i | bit];
+                if a.w == 0.0 && b.w == 0.0 {
+                    None
+                } else if a.w == 0.0 {
+                    Some((b, a))
+                } else {
+                    Some((a, b))
+                }
+            })
+        })
+    }
+
+    /// Projects this frustum onto the given axis, returning the minimum and maximum values of
+    /// the dot product between the axis and points in the frustum.
+    fn project(&self, axis: Vector3) -> (Scalar, Scalar) {
+        let mut min = Scalar::INFINITY;
+        let mut max = Scalar::NEG_INFINITY;
+        for corner in self.corners.iter() {
+            let value = axis.dot(&vec3(corner.x, corner.y, corner.z));
+            if corner.w != 0.0 {
+                min = min.min(value);
+                max = max.max(value);
+            } else if value > 1e-6 * axis.norm() {
+                max = Scalar::INFINITY;
+            } else if value < -1e-6 * axis.norm() {
+                min = Scalar::NEG_INFINITY;
+            }
+        }
+        (min, max)
+    }
+}
+
+#[test]
+fn test_contains() {
+    use crate::conv::{Perspective, ViewLookAt};
+    let view = Motion3::view_look_at(vec3(0.0, 0.0, 0.0), vec3(0.0, 0.0, -1.0));
+    for far_z in [10.0, Scalar::INFINITY] {
+        let proj = Projective3::perspective(1.0, crate::PI / 2.0, 1.0, far_z) * view;
+        let frustum = Frustum3::new(&proj);
+        assert!(frustum.contains(vec3(0.0, 0.0, -2.0)));
+        assert!(frustum.contains(vec3(1.5, -1.5, -2.0)));
+        assert!(!frustum.contains(vec3(2.5, 0.0, -2.0)));
+        assert!(!frustum.contains(vec3(0.0, 0.0, -0.5)));
+        assert!(!frustum.contains(vec3(0.0, 0.0, 2.0)));
+        assert_eq!(frustum.contains(vec3(0.0, 0.0, -20.0)), far_z > 20.0);
+    }
+}
+
+#[test]
+fn test_sphere() {
+    use crate::conv::{Perspective, ViewLookAt};
+    let view = Motion3::view_look_at(vec3(0.0, 0.0, 0.0), vec3(0.0, 0.0, -1.0));
+    for far_z in [10.0, Scalar::INFINITY] {
+        let proj = Projective3::perspective(1.0, crate::PI / 2.0, 1.0, far_z) * view;
+        let frustum = Frustum3::new(&proj);
+        let inside = Sphere3::new(vec3(0.0, 0.0, -5.0), 1.0);
+        let crossing = Sphere3::new(vec3(0.0, 0.0, -1.0), 0.5);
+        let outside = Sphere3::new(vec3(0.0, 0.0, 2.0), 1.0);
+        let corner = Sphere3::new(vec3(1.383, 0.0, -0.076), 0.96);
+        for test in [Frustum3::test_sphere, Frustum3::test_sphere_exact] {
+            assert_eq!(test(&frustum, &inside), Containment::Inside);
+            assert_eq!(test(&frustum, &crossing), Containment::Intersecting);
+            assert_eq!(test(&frustum, &outside), Containment::Outside);
+        }
+        assert_eq!(frustum.test_sphere(&corner), Containment::Intersecting);
+        assert_eq!(frustum.test_sphere_exact(&corner), Containment::Outside);
+    }
+}
+
+#[test]
+fn test_box() {
+    use crate::conv::{Perspective, ViewLookAt};
+    let view = Motion3::view_look_at(vec3(0.0, 0.0, 0.0), vec3(0.0, 0.0, -1.0));
+    for far_z in [10.0, Scalar::INFINITY] {
+        let proj = Projective3::perspective(1.0, crate::PI / 2.0, 1.0, far_z) * view;
+        let frustum = Frustum3::new(&proj);
+        let inside = Box3::from_min_max(vec3(-1.0, -1.0, -4.0), vec3(1.0, 1.0, -2.0));
+        let crossing = Box3::from_min_max(vec3(-1.0, -1.0, -2.0), vec3(1.0, 1.0, 0.0));
+        let outside = Box3::from_min_max(vec3(-1.0, -1.0, 1.0), vec3(1.0, 1.0, 2.0));
+        for test in [Frustum3::test_box, Frustum3::test_box_exact] {
+            assert_eq!(test(&frustum, &inside), Containment::Inside);
+            assert_eq!(test(&frustum, &crossing), Containment::Intersecting);
+            assert_eq!(test(&frustum, &outside), Containment::Outside);
+        }
+
+        // Roll the camera so that the cross-section of the frustum is a diamond, and place a box
+        // just beyond one of its corners
+        let roll = crate::Rotation3::about(
+            vec3(0.0, 0.0, 1.0),
+            crate::Rotation2::from_angle(crate::PI / 4.0),
+        );
+        let frustum = Frustum3::new(&(proj * roll));
+        let corner = Box3::from_min_max(vec3(3.0, -1.0, -2.05), vec3(4.0, 1.0, -1.95));
+        assert_eq!(frustum.test_box(&corner), Containment::Intersecting);
+        assert_eq!(frustum.test_box_exact(&corner), Containment::Outside);
+    }
+}
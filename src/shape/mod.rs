@@ -1,5 +1,15 @@
 mod box2;
 mod box2i;
+mod box3;
+mod frustum3;
+mod plane3;
+mod ray3;
+mod sphere3;
 
 pub use box2::Box2;
-pub use box2i::{size2i, Box2i, Size2i};
\ No newline at end of file
+pub use box2i::{size2i, Box2i, Size2i};
+pub use box3::Box3;
+pub use frustum3::{Containment, Frustum3};
+pub use plane3::Plane3;
+pub use ray3::Ray3;
+pub use sphere3::Sphere3;
@@ -0,0 +1,48 @@
+use crate::{Scalar, Vector3};
+
+/// A plane in three-dimensional space, dividing space into a "front" half-space, where
+/// `normal · p + offset >= 0`, and a "back" half-space.
+#[repr(C)]
+#[derive(Default, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Plane3 {
+    /// The normal of the plane, pointing towards the front half-space. This is usually, but not
+    /// necessarily, a unit vector.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
+    pub normal: Vector3,
+
+    /// The signed distance from the plane to the origin, scaled by the length of `normal`.
+    pub offset: Scalar,
+}
+
+impl Plane3 {
+    /// Constructs a [`Plane3`] from its normal and offset.
+    #[inline]
+    pub const fn new(normal: Vector3, offset: Scalar) -> Self {
+        Self { normal, offset }
+    }
+
+    /// Scales this plane so that its normal is a unit vector. Degenerate planes (with a zero
+    /// normal) are returned unchanged.
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let len = self.normal.norm();
+        if len > 0.0 {
+            Self {
+                normal: self.normal / len,
+                offset: self.offset / len,
+            }
+        } else {
+            *self
+        }
+    }
+
+    /// Gets the signed distance from this plane to the given point, scaled by the length of
+    /// `normal`. This is positive in front of the plane and negative behind it.
+    #[inline]
+    pub fn dist(&self, point: Vector3) -> Scalar {
+        self.normal.dot(&point) + self.offset
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{Scalar, Vector3};
+
+/// A half-line in three-dimensional space.
+#[repr(C)]
+#[derive(Default, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Ray3 {
+    /// The point where the ray starts.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
+    pub origin: Vector3,
+
+    /// The direction of the ray. This is usually, but not necessarily, a unit vector.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
+    pub dir: Vector3,
+}
+
+impl Ray3 {
+    /// Constructs a [`Ray3`] from its origin and direction.
+    #[inline]
+    pub const fn new(origin: Vector3, dir: Vector3) -> Self {
+        Self { origin, dir }
+    }
+
+    /// Gets the point at the given parameter along this ray, i.e. `origin + dir * t`.
+    #[inline]
+    pub fn at(&self, t: Scalar) -> Vector3 {
+        self.origin + self.dir * t
+    }
+}
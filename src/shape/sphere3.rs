@@ -0,0 +1,30 @@
+use crate::{Scalar, Vector3};
+
+/// A ball in three-dimensional space.
+#[repr(C)]
+#[derive(Default, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Sphere3 {
+    /// The center of the sphere.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
+    pub center: Vector3,
+
+    /// The radius of the sphere.
+    pub radius: Scalar,
+}
+
+impl Sphere3 {
+    /// Constructs a [`Sphere3`] from its center and radius.
+    #[inline]
+    pub const fn new(center: Vector3, radius: Scalar) -> Self {
+        Self { center, radius }
+    }
+
+    /// Determines whether this sphere contains the given point.
+    #[inline]
+    pub fn contains(&self, point: Vector3) -> bool {
+        (point - self.center).norm_squared() <= self.radius * self.radius
+    }
+}
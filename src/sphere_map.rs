@@ -0,0 +1,170 @@
+//! This module defines mappings between unit directions and 2D coordinates, as used for
+//! environment maps, panoramas and direction-indexed textures.
+use crate::{math, vec2, vec3, Dir3i, Scalar, Vector2, Vector3, PI};
+
+/// Maps a unit direction to equirectangular (latitude-longitude) coordinates in \[0, 1\] × \[0, 1\].
+///
+/// This follows the conventions of the [`crate::conv`] module: the center of the image corresponds
+/// to negative Z (forward), positive U points towards positive X (right) and `v = 0` corresponds to
+/// positive Y (up).
+pub fn dir_to_equirect(dir: Vector3) -> Vector2 {
+    let u = 0.5 + math::atan2(dir.x, -dir.z) / (2.0 * PI);
+    let v = math::acos(dir.y.clamp(-1.0, 1.0)) / PI;
+    vec2(u, v)
+}
+
+/// Maps equirectangular (latitude-longitude) coordinates to a unit direction. This is the inverse
+/// of [`dir_to_equirect`].
+pub fn equirect_to_dir(uv: Vector2) -> Vector3 {
+    let (sin_phi, cos_phi) = math::sin_cos((uv.x - 0.5) * 2.0 * PI);
+    let (sin_theta, cos_theta) = math::sin_cos(uv.y * PI);
+    vec3(sin_theta * sin_phi, cos_theta, -sin_theta * cos_phi)
+}
+
+/// Maps a unit direction to octahedral coordinates in \[-1, 1\] × \[-1, 1\].
+///
+/// The upper hemisphere (positive Z) maps to the diamond `|u| + |v| <= 1`, and the lower hemisphere
+/// is folded into the corners of the square.
+pub fn dir_to_octahedral(dir: Vector3) -> Vector2 {
+    let l_1 = dir.x.abs() + dir.y.abs() + dir.z.abs();
+    let p = vec2(dir.x / l_1, dir.y / l_1);
+    if dir.z < 0.0 {
+        vec2(
+            (1.0 - p.y.abs()) * sign_not_zero(p.x),
+            (1.0 - p.x.abs()) * sign_not_zero(p.y),
+        )
+    } else {
+        p
+    }
+}
+
+/// Maps octahedral coordinates to a unit direction. This is the inverse of [`dir_to_octahedral`].
+pub fn octahedral_to_dir(uv: Vector2) -> Vector3 {
+    let z = 1.0 - uv.x.abs() - uv.y.abs();
+    let (x, y) = if z < 0.0 {
+        (
+            (1.0 - uv.y.abs()) * sign_not_zero(uv.x),
+            (1.0 - uv.x.abs()) * sign_not_zero(uv.y),
+        )
+    } else {
+        (uv.x, uv.y)
+    };
+    vec3(x, y, z).normalize()
+}
+
+/// Gets the sign of the given value, treating zero as positive.
+#[inline]
+fn sign_not_zero(value: Scalar) -> Scalar {
+    if value < 0.0 {
+        -1.0
+    } else {
+        1.0
+    }
+}
+
+/// Maps a direction to the face of a cube map it falls on, along with its UV coordinates in
+/// \[0, 1\] × \[0, 1\] on that face.
+///
+/// Face UV coordinates follow the convention used by OpenGL, Direct3D, Vulkan and WebGPU, where the
+/// faces are viewed from inside the cube and `v = 0` is the top row of each face (which is towards
+/// positive Z for the positive Y face, and towards negative Y otherwise). The direction does not
+/// need to be normalized.
+pub fn dir_to_cube_face(dir: Vector3) -> (Dir3i, Vector2) {
+    let (x, y, z) = (dir.x.abs(), dir.y.abs(), dir.z.abs());
+    let (face, s, t, m) = if x >= y && x >= z {
+        if dir.x >= 0.0 {
+            (Dir3i::Xp, -dir.z, -dir.y, x)
+        } else {
+            (Dir3i::Xn, dir.z, -dir.y, x)
+        }
+    } else if y >= z {
+        if dir.y >= 0.0 {
+            (Dir3i::Yp, dir.x, dir.z, y)
+        } else {
+            (Dir3i::Yn, dir.x, -dir.z, y)
+        }
+    } else if dir.z >= 0.0 {
+        (Dir3i::Zp, dir.x, -dir.y, z)
+    } else {
+        (Dir3i::Zn, -dir.x, -dir.y, z)
+    };
+    (face, vec2((s / m + 1.0) / 2.0, (t / m + 1.0) / 2.0))
+}
+
+/// Maps a point on the face of a cube map to the unit direction it corresponds to. This is the
+/// inverse of [`dir_to_cube_face`].
+pub fn cube_face_to_dir(face: Dir3i, uv: Vector2) -> Vector3 {
+    let s = 2.0 * uv.x - 1.0;
+    let t = 2.0 * uv.y - 1.0;
+    match face {
+        Dir3i::Xp => vec3(1.0, -t, -s),
+        Dir3i::Xn => vec3(-1.0, -t, s),
+        Dir3i::Yp => vec3(s, 1.0, t),
+        Dir3i::Yn => vec3(s, -1.0, -t),
+        Dir3i::Zp => vec3(s, -t, 1.0),
+        Dir3i::Zn => vec3(-s, -t, -1.0),
+    }
+    .normalize()
+}
+
+#[test]
+fn test_equirect() {
+    approx::assert_relative_eq!(dir_to_equirect(vec3(0.0, 0.0, -1.0)), vec2(0.5, 0.5));
+    approx::assert_relative_eq!(dir_to_equirect(vec3(1.0, 0.0, 0.0)), vec2(0.75, 0.5));
+    approx::assert_relative_eq!(dir_to_equirect(vec3(0.0, 1.0, 0.0)).y, 0.0);
+    for dir in [
+        vec3(0.3, -0.5, 0.8),
+        vec3(-0.9, 0.2, -0.1),
+        vec3(0.1, 0.95, -0.2),
+        vec3(-0.2, -0.3, -0.9),
+        vec3(0.6, 0.1, 0.3),
+        vec3(0.0, 0.0, -1.0),
+    ] {
+        let dir = dir.normalize();
+        approx::assert_relative_eq!(equirect_to_dir(dir_to_equirect(dir)), dir, epsilon = 1e-5);
+    }
+}
+
+#[test]
+fn test_octahedral() {
+    approx::assert_relative_eq!(dir_to_octahedral(vec3(0.0, 0.0, 1.0)), vec2(0.0, 0.0));
+    approx::assert_relative_eq!(dir_to_octahedral(vec3(0.0, 0.0, -1.0)), vec2(1.0, 1.0));
+    for dir in [
+        vec3(0.3, -0.5, 0.8),
+        vec3(-0.9, 0.2, -0.1),
+        vec3(0.1, 0.95, -0.2),
+        vec3(-0.2, -0.3, -0.9),
+        vec3(0.6, 0.1, 0.3),
+        vec3(0.0, 0.0, -1.0),
+    ] {
+        let dir = dir.normalize();
+        let uv = dir_to_octahedral(dir);
+        assert!(uv.x.abs() <= 1.0 && uv.y.abs() <= 1.0);
+        approx::assert_relative_eq!(octahedral_to_dir(uv), dir, epsilon = 1e-5);
+    }
+}
+
+#[test]
+fn test_cube_face() {
+    assert_eq!(
+        dir_to_cube_face(vec3(0.0, 0.0, -1.0)),
+        (Dir3i::Zn, vec2(0.5, 0.5))
+    );
+    assert_eq!(
+        dir_to_cube_face(vec3(1.0, 1.0, -1.0)),
+        (Dir3i::Xp, vec2(1.0, 0.0))
+    );
+    for dir in [
+        vec3(0.3, -0.5, 0.8),
+        vec3(-0.9, 0.2, -0.1),
+        vec3(0.1, 0.95, -0.2),
+        vec3(-0.2, -0.3, -0.9),
+        vec3(0.6, 0.1, 0.3),
+        vec3(0.0, 0.0, -1.0),
+    ] {
+        let dir = dir.normalize();
+        let (face, uv) = dir_to_cube_face(dir);
+        assert!(uv.x >= 0.0 && uv.x <= 1.0 && uv.y >= 0.0 && uv.y <= 1.0);
+        approx::assert_relative_eq!(cube_face_to_dir(face, uv), dir, epsilon = 1e-5);
+    }
+}
@@ -1,11 +1,13 @@
 use crate::Scalar;
 use diffvec::{Diff, PolyMappable};
-use std::ops::{Add, AddAssign, Div, DivAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Sub, SubAssign};
 
 /// Represents a span of time using a fixed-point representation that ensures consistent precision
 /// across all range of values. This is similar to [`core::time::Duration`], but more compact
 /// and with a smaller range.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Duration {
     nanos: u64,
 }
@@ -77,7 +79,7 @@ impl Duration {
         if secs < 0.0 {
             Err(TryFromFloatError::Negative)
         } else {
-            let src = std::time::Duration::try_from_secs_f32(secs)
+            let src = core::time::Duration::try_from_secs_f32(secs)
                 .map_err(|_| TryFromFloatError::OverflowOrNan)?;
             Ok(src.try_into()?)
         }
@@ -88,7 +90,7 @@ impl Duration {
         if secs < 0.0 {
             Err(TryFromFloatError::Negative)
         } else {
-            let src = std::time::Duration::try_from_secs_f64(secs)
+            let src = core::time::Duration::try_from_secs_f64(secs)
                 .map_err(|_| TryFromFloatError::OverflowOrNan)?;
             Ok(src.try_into()?)
         }
@@ -133,15 +135,15 @@ impl Duration {
     }
 }
 
-impl From<Duration> for std::time::Duration {
+impl From<Duration> for core::time::Duration {
     fn from(value: Duration) -> Self {
-        std::time::Duration::from_nanos(value.nanos)
+        core::time::Duration::from_nanos(value.nanos)
     }
 }
 
-impl TryFrom<std::time::Duration> for Duration {
+impl TryFrom<core::time::Duration> for Duration {
     type Error = TryFromError;
-    fn try_from(value: std::time::Duration) -> Result<Self, TryFromError> {
+    fn try_from(value: core::time::Duration) -> Result<Self, TryFromError> {
         Ok(Duration::from_nanos(
             value.as_nanos().try_into().map_err(|_| TryFromError)?,
         ))
@@ -195,9 +197,9 @@ impl diffvec::Differentiate<Scalar> for Instant {
     }
 }
 
-impl std::fmt::Debug for Duration {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        <std::time::Duration as std::fmt::Debug>::fmt(&(*self).into(), f)
+impl core::fmt::Debug for Duration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <core::time::Duration as core::fmt::Debug>::fmt(&(*self).into(), f)
     }
 }
 
@@ -217,7 +219,7 @@ impl From<TryFromError> for TryFromFloatError {
     }
 }
 
-/// An error which can be returned when converting a [`std::time::Duration`] into a [`Duration`].
+/// An error which can be returned when converting a [`core::time::Duration`] into a [`Duration`].
 #[derive(thiserror::Error, Debug)]
 #[error("duration overflow")]
 pub struct TryFromError;
@@ -248,3 +250,10 @@ impl<Poly: PolyMappable> DiffDurationExt<Poly> for Diff<Poly, Duration, Scalar>
         })
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use serde_test::{assert_tokens, Token};
+    assert_tokens(&Duration::from_millis(1500), &[Token::U64(1_500_000_000)]);
+}
@@ -4,12 +4,14 @@ use crate::{vec2, Matrix2, Rotation2, Scalar, Vector2};
 #[repr(C)]
 #[derive(PartialEq, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct Motion2 {
     /// The rotation component of this transform, applied before translation.
     pub rotation: Rotation2,
 
     /// The offset for the translation component of this transform, applied after rotation.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector2Def"))]
     pub offset: Vector2,
 }
 
@@ -77,6 +79,7 @@ impl core::ops::Mul<Vector2> for Motion2 {
 #[repr(C)]
 #[derive(PartialEq, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct Similarity2 {
     /// The rotation component of this transform, applied before translation.
@@ -87,6 +90,7 @@ pub struct Similarity2 {
 
     /// The offset for the translation component of this transform, applied after rotation and
     /// scaling.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector2Def"))]
     pub offset: Vector2,
 }
 
@@ -180,13 +184,16 @@ impl core::ops::Mul<Vector2> for Similarity2 {
 #[repr(C)]
 #[derive(PartialEq, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct Affine2 {
     /// The linear component of this transform, applied before translation.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Matrix2Def"))]
     pub linear: Matrix2,
 
     /// The offset for the translation component of this transform, applied after the linear
     /// component.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector2Def"))]
     pub offset: Vector2,
 }
 
@@ -1,5 +1,5 @@
 use crate::{vec2, vec2i, Motion2, Rotation2i, Scalar, Vector2, Vector2i};
-use std::num::NonZeroI32;
+use core::num::NonZeroI32;
 
 /// A transform in discrete two-dimensional space consisting of rotation and translation.
 ///
@@ -8,6 +8,7 @@ use std::num::NonZeroI32;
 #[repr(C)]
 #[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub struct Motion2i {
     /// The rotation component of this transform, applied before translation.
@@ -97,6 +98,7 @@ impl From<Motion2i> for Motion2 {
 /// reflection and translation, i.e. a transform that preserves orthogonality of the axes.
 #[repr(C)]
 #[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ortho2i {
     /// The scaling applied to the X axis. Can be negative to reflect about Y axis.
     scaling_x: NonZeroI32,
@@ -195,7 +197,7 @@ impl core::ops::Mul<Ortho2i> for Ortho2i {
         let mut scaling_x = self.scaling_x;
         let mut scaling_y = self.scaling_y;
         if rhs.swap_axes {
-            std::mem::swap(&mut scaling_x, &mut scaling_y);
+            core::mem::swap(&mut scaling_x, &mut scaling_y);
         }
         let scaling_x = NonZeroI32::try_from(scaling_x.get() * rhs.scaling_x.get()).unwrap();
         let scaling_y = NonZeroI32::try_from(scaling_y.get() * rhs.scaling_y.get()).unwrap();
@@ -215,7 +217,7 @@ impl core::ops::Mul<Vector2i> for Ortho2i {
         let mut x = rhs.x * self.scaling_x.get();
         let mut y = rhs.y * self.scaling_y.get();
         if self.swap_axes {
-            std::mem::swap(&mut x, &mut y);
+            core::mem::swap(&mut x, &mut y);
         }
         vec2i(x, y) + self.offset
     }
@@ -227,7 +229,7 @@ impl core::ops::Mul<Vector2> for Ortho2i {
         let mut x = rhs.x * (self.scaling_x.get() as Scalar);
         let mut y = rhs.y * (self.scaling_y.get() as Scalar);
         if self.swap_axes {
-            std::mem::swap(&mut x, &mut y);
+            core::mem::swap(&mut x, &mut y);
         }
         vec2(x, y) + self.offset.to_float()
     }
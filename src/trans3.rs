@@ -4,12 +4,14 @@ use crate::{vec3, Matrix3, Rotation3, Scalar, Vector3};
 #[repr(C)]
 #[derive(PartialEq, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct Motion3 {
     /// The rotation component of this transform, applied before translation.
     pub rotation: Rotation3,
 
     /// The offset for the translation component of this transform, applied after rotation.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
     pub offset: Vector3,
 }
 
@@ -101,6 +103,7 @@ impl core::ops::Mul<Vector3> for Motion3 {
 #[repr(C)]
 #[derive(PartialEq, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct Similarity3 {
     /// The rotation component of this transform, applied before translation.
@@ -111,6 +114,7 @@ pub struct Similarity3 {
 
     /// The offset for the translation component of this transform, applied after rotation and
     /// scaling.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
     pub offset: Vector3,
 }
 
@@ -207,13 +211,16 @@ impl core::ops::Mul<Vector3> for Similarity3 {
 #[repr(C)]
 #[derive(PartialEq, Copy, Clone, Debug)]
 #[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct Affine3 {
     /// The linear component of this transform, applied before translation.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Matrix3Def"))]
     pub linear: Matrix3,
 
     /// The offset for the translation component of this transform, applied after the linear
     /// component.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
     pub offset: Vector3,
 }
 
@@ -341,3 +348,43 @@ fn test_affine_compose() {
     approx::assert_relative_eq!(a * (b * (c * x)), ((a * b) * c) * x, epsilon = 0.001);
     approx::assert_relative_eq!(a * (b * (c * x)), (a * (b * c)) * x, epsilon = 0.001);
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use serde_test::{assert_tokens, Token};
+    let motion = Motion3 {
+        rotation: Rotation3::new_unchecked(0.6, 0.8, 0.0, 0.0),
+        offset: vec3(1.0, -2.0, 3.5),
+    };
+    assert_tokens(
+        &motion,
+        &[
+            Token::Struct { name: "Motion3", len: 2 },
+            Token::Str("rotation"),
+            Token::Struct { name: "Rotation3", len: 2 },
+            Token::Str("x_y_z"),
+            Token::Struct { name: "Vector3", len: 3 },
+            Token::Str("x"),
+            Token::F32(0.8),
+            Token::Str("y"),
+            Token::F32(0.0),
+            Token::Str("z"),
+            Token::F32(0.0),
+            Token::StructEnd,
+            Token::Str("w"),
+            Token::F32(0.6),
+            Token::StructEnd,
+            Token::Str("offset"),
+            Token::Struct { name: "Vector3", len: 3 },
+            Token::Str("x"),
+            Token::F32(1.0),
+            Token::Str("y"),
+            Token::F32(-2.0),
+            Token::Str("z"),
+            Token::F32(3.5),
+            Token::StructEnd,
+            Token::StructEnd,
+        ],
+    );
+}
@@ -0,0 +1,295 @@
+use crate::{math, vec2, Motion2, Motion3, Rotation2, Rotation3, Scalar, Vector2, Vector3};
+
+/// A rigid velocity in two-dimensional space, i.e. an element of the tangent space of [`Motion2`]
+/// at the identity.
+#[repr(C)]
+#[derive(
+    Default,
+    PartialEq,
+    Copy,
+    Clone,
+    Debug,
+    derive_more::Add,
+    derive_more::Sub,
+    derive_more::Neg,
+    derive_more::Mul,
+)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Twist2 {
+    /// The counter-clockwise angular velocity, in radians.
+    pub angular: Scalar,
+
+    /// The linear velocity.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector2Def"))]
+    pub linear: Vector2,
+}
+
+impl Twist2 {
+    /// Constructs a [`Twist2`] from its angular and linear velocities.
+    pub const fn new(angular: Scalar, linear: Vector2) -> Self {
+        Self { angular, linear }
+    }
+}
+
+/// A rigid velocity in three-dimensional space, i.e. an element of the tangent space of [`Motion3`]
+/// at the identity.
+#[repr(C)]
+#[derive(
+    Default,
+    PartialEq,
+    Copy,
+    Clone,
+    Debug,
+    derive_more::Add,
+    derive_more::Sub,
+    derive_more::Neg,
+    derive_more::Mul,
+)]
+#[cfg_attr(feature = "serdere", derive(serdere::Serialize, serdere::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+pub struct Twist3 {
+    /// The angular velocity. Its direction is the axis of rotation and its magnitude is the
+    /// angular speed, in radians, following the right-hand rule.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
+    pub angular: Vector3,
+
+    /// The linear velocity.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::Vector3Def"))]
+    pub linear: Vector3,
+}
+
+impl Twist3 {
+    /// Constructs a [`Twist3`] from its angular and linear velocities.
+    pub const fn new(angular: Vector3, linear: Vector3) -> Self {
+        Self { angular, linear }
+    }
+}
+
+/// The angle below which series approximations are used in place of exact formulas that are
+/// numerically unstable near zero.
+const SMALL_ANGLE: Scalar = 1e-3;
+
+/// Rotates a vector by half of the given angle, scaled by `sin(half_angle) / half_angle`.
+fn half_rotate_sinc(angle: Scalar, vec: Vector2) -> Vector2 {
+    let half = angle / 2.0;
+    let (sin, cos) = math::sin_cos(half);
+    let sinc = if half.abs() > SMALL_ANGLE {
+        sin / half
+    } else {
+        1.0 - half * half / 6.0
+    };
+    vec2(cos * vec.x - sin * vec.y, sin * vec.x + cos * vec.y) * sinc
+}
+
+/// Perturbations are right-multiplied motions, given as rigid velocities in the local frame of the
+/// motion (see [`Motion2::exp`]).
+impl diffvec::Differentiate<Twist2> for Motion2 {
+    fn perturb_mut(&mut self, amount: &Twist2) {
+        *self = *self * Motion2::exp(*amount);
+    }
+}
+
+/// Perturbations are right-multiplied motions, given as rigid velocities in the local frame of the
+/// motion (see [`Motion3::exp`]).
+impl diffvec::Differentiate<Twist3> for Motion3 {
+    fn perturb_mut(&mut self, amount: &Twist3) {
+        *self = *self * Motion3::exp(*amount);
+    }
+}
+
+impl Motion2 {
+    /// Gets the motion obtained by following the given rigid velocity for one unit of time. This
+    /// is the exponential map for rigid motions.
+    pub fn exp(twist: Twist2) -> Self {
+        Self {
+            rotation: Rotation2::from_angle(twist.angular),
+            offset: half_rotate_sinc(twist.angular, twist.linear),
+        }
+    }
+
+    /// Gets the rigid velocity which, followed for one unit of time, gives this motion. The
+    /// angular velocity is in \[-π, π\]. This is the inverse of [`Motion2::exp`].
+    pub fn log(&self) -> Twist2 {
+        let (sin, cos) = self.rotation.angle_sin_cos();
+        let angular = math::atan2(sin, cos);
+        let half = angular / 2.0;
+        let (h_sin, h_cos) = math::sin_cos(half);
+        let inv_sinc = if half.abs() > SMALL_ANGLE {
+            half / h_sin
+        } else {
+            1.0 + half * half / 6.0
+        };
+        let offset = self.offset;
+        let linear = vec2(
+            h_cos * offset.x + h_sin * offset.y,
+            h_cos * offset.y - h_sin * offset.x,
+        ) * inv_sinc;
+        Twist2 { angular, linear }
+    }
+
+    /// Applies the adjoint map of this motion to a rigid velocity. This converts a rigid velocity
+    /// expressed in the local frame of this motion into the parent frame, such that
+    /// `self * Motion2::exp(twist) == Motion2::exp(self.adjoint(twist)) * self`.
+    pub fn adjoint(&self, twist: Twist2) -> Twist2 {
+        let linear = self.rotation * twist.linear;
+        Twist2 {
+            angular: twist.angular,
+            linear: linear + vec2(self.offset.y, -self.offset.x) * twist.angular,
+        }
+    }
+}
+
+impl Motion3 {
+    /// Gets the motion obtained by following the given rigid velocity for one unit of time. This
+    /// is the exponential map for rigid motions.
+    pub fn exp(twist: Twist3) -> Self {
+        let omega = twist.angular;
+        let angle_sqr = omega.norm_squared();
+        let angle = math::sqrt(angle_sqr);
+        let (a, b) = if angle > SMALL_ANGLE {
+            let (sin, cos) = math::sin_cos(angle);
+            ((1.0 - cos) / angle_sqr, (angle - sin) / (angle_sqr * angle))
+        } else {
+            (0.5 - angle_sqr / 24.0, 1.0 / 6.0 - angle_sqr / 120.0)
+        };
+        let rotation = Rotation3::from_euler(omega);
+        let v = twist.linear;
+        let w_v = omega.cross(&v);
+        Self {
+            rotation,
+            offset: v + w_v * a + omega.cross(&w_v) * b,
+        }
+    }
+
+    /// Gets the rigid velocity which, followed for one unit of time, gives this motion. The
+    /// magnitude of the angular velocity is in \[0, π\]. This is the inverse of [`Motion3::exp`].
+    pub fn log(&self) -> Twist3 {
+        let omega = self.rotation.to_euler();
+        let angle_sqr = omega.norm_squared();
+        let angle = math::sqrt(angle_sqr);
+        let c = if angle > SMALL_ANGLE {
+            let (sin, cos) = math::sin_cos(angle);
+            (1.0 - angle * sin / (2.0 * (1.0 - cos))) / angle_sqr
+        } else {
+            1.0 / 12.0 + angle_sqr / 720.0
+        };
+        let t = self.offset;
+        let w_t = omega.cross(&t);
+        Twist3 {
+            angular: omega,
+            linear: t - w_t * 0.5 + omega.cross(&w_t) * c,
+        }
+    }
+
+    /// Applies the adjoint map of this motion to a rigid velocity. This converts a rigid velocity
+    /// expressed in the local frame of this motion into the parent frame, such that
+    /// `self * Motion3::exp(twist) == Motion3::exp(self.adjoint(twist)) * self`.
+    pub fn adjoint(&self, twist: Twist3) -> Twist3 {
+        let angular = self.rotation * twist.angular;
+        Twist3 {
+            angular,
+            linear: self.rotation * twist.linear + self.offset.cross(&angular),
+        }
+    }
+}
+
+#[cfg(test)]
+fn assert_motion3_eq(a: Motion3, b: Motion3) {
+    approx::assert_relative_eq!(a.offset, b.offset, epsilon = 1e-4);
+    let point = crate::vec3(1.0, -2.0, 0.5);
+    approx::assert_relative_eq!(a * point, b * point, epsilon = 1e-4);
+}
+
+#[test]
+fn test_motion2_exp_log() {
+    for twist in [
+        Twist2::new(0.0, vec2(1.0, 2.0)),
+        Twist2::new(1e-5, vec2(-1.0, 0.5)),
+        Twist2::new(1.3, vec2(0.2, -0.7)),
+        Twist2::new(-3.0, vec2(4.0, 1.0)),
+    ] {
+        let res = Motion2::exp(twist).log();
+        approx::assert_relative_eq!(res.angular, twist.angular, epsilon = 1e-5);
+        approx::assert_relative_eq!(res.linear, twist.linear, epsilon = 1e-4);
+    }
+
+    // A quarter turn about the origin, moving along a circular arc
+    let angle = crate::PI / 2.0;
+    let motion = Motion2::exp(Twist2::new(angle, vec2(angle, 0.0)));
+    approx::assert_relative_eq!(motion.offset, vec2(1.0, 1.0), epsilon = 1e-5);
+}
+
+#[test]
+fn test_motion2_adjoint() {
+    let motion = Motion2 {
+        rotation: Rotation2::from_angle(0.8),
+        offset: vec2(2.0, -1.0),
+    };
+    let twist = Twist2::new(0.4, vec2(-0.3, 1.2));
+    let a = motion * Motion2::exp(twist);
+    let b = Motion2::exp(motion.adjoint(twist)) * motion;
+    approx::assert_relative_eq!(a.offset, b.offset, epsilon = 1e-5);
+    approx::assert_relative_eq!(a * vec2(1.0, 1.0), b * vec2(1.0, 1.0), epsilon = 1e-5);
+}
+
+#[test]
+fn test_motion3_exp_log() {
+    use crate::vec3;
+    for twist in [
+        Twist3::new(vec3(0.0, 0.0, 0.0), vec3(1.0, 2.0, 3.0)),
+        Twist3::new(vec3(1e-5, 0.0, -1e-5), vec3(-1.0, 0.5, 0.0)),
+        Twist3::new(vec3(0.3, -1.2, 0.8), vec3(0.2, -0.7, 1.5)),
+        Twist3::new(vec3(0.0, 2.9, 0.0), vec3(4.0, 1.0, -2.0)),
+    ] {
+        let res = Motion3::exp(twist).log();
+        approx::assert_relative_eq!(res.angular, twist.angular, epsilon = 1e-4);
+        approx::assert_relative_eq!(res.linear, twist.linear, epsilon = 1e-4);
+    }
+
+    // Without angular velocity, the exponential is a translation
+    let motion = Motion3::exp(Twist3::new(vec3(0.0, 0.0, 0.0), vec3(1.0, 2.0, 3.0)));
+    assert_motion3_eq(motion, Motion3::translate(vec3(1.0, 2.0, 3.0)));
+
+    // Rotation without translation
+    let omega = vec3(0.3, -1.2, 0.8);
+    let motion = Motion3::exp(Twist3::new(omega, vec3(0.0, 0.0, 0.0)));
+    assert_motion3_eq(motion, Rotation3::from_euler(omega).into());
+}
+
+#[test]
+fn test_perturb() {
+    use crate::vec3;
+    use diffvec::Differentiate;
+    let motion = Motion3 {
+        rotation: Rotation3::from_euler(vec3(0.5, 0.1, -0.7)),
+        offset: vec3(2.0, -1.0, 0.5),
+    };
+    let mut res = motion;
+    res.perturb_mut(&Twist3::new(vec3(0.0, 0.0, 0.0), vec3(0.0, 0.0, 1.0)));
+    assert_motion3_eq(res, motion * Motion3::translate(vec3(0.0, 0.0, 1.0)));
+
+    let motion = Motion2 {
+        rotation: Rotation2::from_angle(0.8),
+        offset: vec2(2.0, -1.0),
+    };
+    let mut res = motion;
+    res.perturb_mut(&Twist2::new(0.0, vec2(1.0, 0.0)));
+    approx::assert_relative_eq!(res.offset, motion * vec2(1.0, 0.0), epsilon = 1e-5);
+}
+
+#[test]
+fn test_motion3_adjoint() {
+    use crate::vec3;
+    let motion = Motion3 {
+        rotation: Rotation3::from_euler(vec3(0.5, 0.1, -0.7)),
+        offset: vec3(2.0, -1.0, 0.5),
+    };
+    let twist = Twist3::new(vec3(0.4, -0.2, 0.3), vec3(-0.3, 1.2, 0.1));
+    assert_motion3_eq(
+        motion * Motion3::exp(twist),
+        Motion3::exp(motion.adjoint(twist)) * motion,
+    );
+}
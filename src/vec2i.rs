@@ -20,6 +20,7 @@ use crate::{vec2, Axis2, Dir1, Vector2};
     derive_more::DivAssign,
 )]
 #[cfg_attr(feature = "serdere", derive(serdere::Deserialize, serdere::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct Vector2i {
     pub x: i32,
@@ -50,7 +51,7 @@ impl core::ops::Index<Axis2> for Vector2i {
     type Output = i32;
     #[inline]
     fn index(&self, axis: Axis2) -> &i32 {
-        unsafe { std::mem::transmute::<&Vector2i, &[i32; 2]>(self).get_unchecked(axis.index()) }
+        unsafe { core::mem::transmute::<&Vector2i, &[i32; 2]>(self).get_unchecked(axis.index()) }
     }
 }
 
@@ -58,14 +59,14 @@ impl core::ops::IndexMut<Axis2> for Vector2i {
     #[inline]
     fn index_mut(&mut self, axis: Axis2) -> &mut i32 {
         unsafe {
-            std::mem::transmute::<&mut Vector2i, &mut [i32; 2]>(self)
+            core::mem::transmute::<&mut Vector2i, &mut [i32; 2]>(self)
                 .get_unchecked_mut(axis.index())
         }
     }
 }
 
-impl std::fmt::Debug for Vector2i {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for Vector2i {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("vec2i")
             .field(&self.x)
             .field(&self.y)
@@ -77,16 +78,17 @@ impl std::fmt::Debug for Vector2i {
 #[repr(u8)]
 #[derive(cantor::Finite, Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serdere", derive(serdere::Deserialize, serdere::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub enum Dir2i {
     #[default]
-    #[cfg_attr(feature = "serdere", serde(rename = "xp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xp"))]
     Xp = 0,
-    #[cfg_attr(feature = "serdere", serde(rename = "xn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xn"))]
     Xn = 1,
-    #[cfg_attr(feature = "serdere", serde(rename = "yp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "yp"))]
     Yp = 2,
-    #[cfg_attr(feature = "serdere", serde(rename = "yn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "yn"))]
     Yn = 3,
 }
 
@@ -94,19 +96,19 @@ impl Dir2i {
     /// Constructs a direction based on its axis and polarity along that axis.
     #[inline]
     pub fn new(axis: Axis2, polarity: Dir1) -> Self {
-        unsafe { std::mem::transmute(((axis as u8) << 1) + polarity as u8) }
+        unsafe { core::mem::transmute(((axis as u8) << 1) + polarity as u8) }
     }
 
     /// Gets the axis this direction is on.
     #[inline]
     pub fn axis(self) -> Axis2 {
-        unsafe { std::mem::transmute((self as u8) >> 1) }
+        unsafe { core::mem::transmute((self as u8) >> 1) }
     }
 
     /// Gets the polairity of this direction along its axis.
     #[inline]
     pub fn polarity(self) -> Dir1 {
-        unsafe { std::mem::transmute((self as u8) & 0b1) }
+        unsafe { core::mem::transmute((self as u8) & 0b1) }
     }
 }
 
@@ -20,6 +20,7 @@ use crate::{vec3, Axis3, Dir1, Vector3};
     derive_more::DivAssign,
 )]
 #[cfg_attr(feature = "serdere", derive(serdere::Deserialize, serdere::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
 pub struct Vector3i {
     pub x: i32,
@@ -51,7 +52,7 @@ impl core::ops::Index<Axis3> for Vector3i {
     type Output = i32;
     #[inline]
     fn index(&self, axis: Axis3) -> &i32 {
-        unsafe { std::mem::transmute::<&Vector3i, &[i32; 3]>(self).get_unchecked(axis.index()) }
+        unsafe { core::mem::transmute::<&Vector3i, &[i32; 3]>(self).get_unchecked(axis.index()) }
     }
 }
 
@@ -59,14 +60,14 @@ impl core::ops::IndexMut<Axis3> for Vector3i {
     #[inline]
     fn index_mut(&mut self, axis: Axis3) -> &mut i32 {
         unsafe {
-            std::mem::transmute::<&mut Vector3i, &mut [i32; 3]>(self)
+            core::mem::transmute::<&mut Vector3i, &mut [i32; 3]>(self)
                 .get_unchecked_mut(axis.index())
         }
     }
 }
 
-impl std::fmt::Debug for Vector3i {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for Vector3i {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("vec3i")
             .field(&self.x)
             .field(&self.y)
@@ -79,20 +80,21 @@ impl std::fmt::Debug for Vector3i {
 #[repr(u8)]
 #[derive(cantor::Finite, Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serdere", derive(serdere::Deserialize, serdere::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub enum Dir3i {
     #[default]
-    #[cfg_attr(feature = "serdere", serde(rename = "xp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xp"))]
     Xp = 0,
-    #[cfg_attr(feature = "serdere", serde(rename = "xn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "xn"))]
     Xn = 1,
-    #[cfg_attr(feature = "serdere", serde(rename = "yp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "yp"))]
     Yp = 2,
-    #[cfg_attr(feature = "serdere", serde(rename = "yn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "yn"))]
     Yn = 3,
-    #[cfg_attr(feature = "serdere", serde(rename = "zp"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "zp"))]
     Zp = 4,
-    #[cfg_attr(feature = "serdere", serde(rename = "zn"))]
+    #[cfg_attr(any(feature = "serdere", feature = "serde"), serde(rename = "zn"))]
     Zn = 5,
 }
 
@@ -100,19 +102,19 @@ impl Dir3i {
     /// Constructs a direction based on its axis and polarity along that axis.
     #[inline]
     pub fn new(axis: Axis3, polarity: Dir1) -> Self {
-        unsafe { std::mem::transmute(((axis as u8) << 1) + polarity as u8) }
+        unsafe { core::mem::transmute(((axis as u8) << 1) + polarity as u8) }
     }
 
     /// Gets the axis this direction is on.
     #[inline]
     pub fn axis(self) -> Axis3 {
-        unsafe { std::mem::transmute((self as u8) >> 1) }
+        unsafe { core::mem::transmute((self as u8) >> 1) }
     }
 
     /// Gets the polairity of this direction along its axis.
     #[inline]
     pub fn polarity(self) -> Dir1 {
-        unsafe { std::mem::transmute((self as u8) & 0b1) }
+        unsafe { core::mem::transmute((self as u8) & 0b1) }
     }
 }
 